@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+const N: u32 = 64;
+
+fn main() {
+    use vgi::vulkan::{layers, Builder};
+    use vulkano::{
+        buffer::{cpu_access::CpuAccessibleBuffer, BufferUsage},
+        command_buffer::{
+            AutoCommandBufferBuilder, CommandBufferUsage, PrimaryCommandBuffer,
+        },
+        descriptor::{descriptor_set::PersistentDescriptorSet, PipelineLayoutAbstract},
+        format::Format,
+        sync::GpuFuture,
+    };
+
+    // create the vulkan container, we are not going to use the target image.
+    let vko = {
+        let layers = layers().expect("fail querying available layers");
+        Builder::new()
+            .unwrap()
+            .with_layers(layers.iter().map(|l| l.name().to_string()))
+            .with_extensions(None)
+            .build_for_buffer([1, 1], Format::R8G8B8A8Unorm)
+            .expect("fail creating Vulkan instance/device")
+    };
+    let queue = Arc::clone(vko.to_queues().iter().next().unwrap());
+
+    let input: Vec<u32> = (1..=N).collect();
+    let size_bytes = (input.len() * std::mem::size_of::<u32>()) as u64;
+
+    // input is staged in host memory and then copied into device local buffer.
+    let staging = CpuAccessibleBuffer::from_iter(
+        vko.to_device(),
+        BufferUsage::transfer_source(),
+        false,
+        input.iter().map(|x| x.to_ne_bytes()).flatten(),
+    )
+    .unwrap();
+    let data = vko.create_storage_buffer(size_bytes, true).unwrap();
+
+    let cs = cs::Shader::load(vko.to_device()).unwrap();
+    let pipeline = vko
+        .create_compute_pipeline(&cs.main_entry_point(), &())
+        .unwrap();
+    let set = {
+        let layout = pipeline.layout().descriptor_set_layout(0).unwrap();
+        Arc::new(
+            PersistentDescriptorSet::start(layout.clone())
+                .add_buffer(data.clone())
+                .unwrap()
+                .build()
+                .unwrap(),
+        )
+    };
+
+    // upload the input, in its own submission.
+    let mut builder = AutoCommandBufferBuilder::primary(
+        vko.to_device(),
+        queue.family(),
+        CommandBufferUsage::OneTimeSubmit,
+    )
+    .unwrap();
+    builder.copy_buffer(staging.clone(), data.clone()).unwrap();
+    builder
+        .build()
+        .unwrap()
+        .execute(queue.clone())
+        .unwrap()
+        .then_signal_fence_and_flush()
+        .unwrap()
+        .wait(None)
+        .unwrap();
+
+    vko.dispatch(&pipeline, set, [1, 1, 1], &queue).unwrap();
+
+    let output: Vec<u32> = vko
+        .read_storage_buffer(&data, &queue)
+        .unwrap()
+        .chunks(4)
+        .map(|b| u32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+
+    let mut acc = 0;
+    for (x, y) in input.into_iter().zip(output.into_iter()) {
+        acc += x;
+        assert_eq!(acc, y, "prefix-sum mismatch");
+    }
+    println!("prefix-sum of 1..={} is ok, total {}", N, acc);
+}
+
+// Hillis-Steele inclusive scan within a single work-group.
+mod cs {
+    vulkano_shaders::shader! {
+        ty: "compute",
+        src: "
+#version 450
+
+layout(local_size_x = 64, local_size_y = 1, local_size_z = 1) in;
+
+layout(set = 0, binding = 0) buffer Data {
+    uint data[];
+} buf;
+
+shared uint tmp[64];
+
+void main() {
+    uint idx = gl_LocalInvocationID.x;
+    tmp[idx] = buf.data[idx];
+    barrier();
+
+    for (uint off = 1; off < 64; off *= 2) {
+        uint val = idx >= off ? tmp[idx - off] : 0;
+        barrier();
+        tmp[idx] += val;
+        barrier();
+    }
+
+    buf.data[idx] = tmp[idx];
+}
+            "
+    }
+}
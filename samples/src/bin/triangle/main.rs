@@ -1,8 +1,8 @@
-use std::sync::Arc;
-
 fn main() {
     use vgi::vulkan::{layers, Builder};
     use vulkano::buffer::{cpu_access::CpuAccessibleBuffer, BufferUsage};
+    use vulkano::format::Format;
+    use vulkano::render_pass::{LoadOp, StoreOp};
 
     // create the vulkan container
     let mut vk = {
@@ -62,34 +62,18 @@ fn main() {
     // output of the graphics pipeline will go. It describes the layout of the images
     // where the colors, depth and/or stencil information will be written.
 
-    let render_pass = Arc::new(
-        vulkano::single_pass_renderpass!(
-            vk.to_device(),
-            attachments: {
-                // `color` is a custom name we give to the first and only attachment.
-                color: {
-                    // `load: Clear` means that we ask the GPU to clear the content of this
-                    // attachment at the start of the drawing.
-                    load: Clear,
-                    // `store: Store` means that we ask the GPU to store the output of the draw
-                    // in the actual image. We could also ask it to discard the result.
-                    store: Store,
-                    // `format: <ty>` indicates the type of the format of the image. This has to
-                    // be one of the types of the `vulkano::format` module (or alternatively one
-                    // of your structs that implements the `FormatDesc` trait). Here we use the
-                    // same format as the swapchain.
-                    format: vk.to_swapchain().format(),
-                    // TODO:
-                    samples: 1,
-                }
-            },
-            pass: {
-                // We use the attachment named `color` as the one and only color attachment.
-                color: [color],
-                // No depth-stencil attachment is indicated with empty brackets.
-                depth_stencil: {}
-            }
+    let render_pass = vk
+        .create_render_pass(vk.to_swapchain().format(), LoadOp::Clear, StoreOp::Store)
+        .expect("fail creating render pass");
+
+    // Same as above, with a depth attachment for scenes that need depth testing.
+    // `D16Unorm` is guaranteed to be supported as depth attachment.
+    let _depth_render_pass = vk
+        .create_depth_render_pass(
+            vk.to_swapchain().format(),
+            Format::D16Unorm,
+            LoadOp::Clear,
+            StoreOp::Store,
         )
-        .unwrap(),
-    );
+        .expect("fail creating depth render pass");
 }
@@ -0,0 +1,178 @@
+use vulkano::{
+    command_buffer::sys::{
+        UnsafeCommandBufferBuilder, UnsafeCommandBufferBuilderPipelineBarrier,
+    },
+    image::{ImageAccess, ImageLayout},
+    sync::{AccessFlags, PipelineStages},
+};
+
+/// Return the minimal (source, destination) access masks for transitioning an
+/// image from `old_layout` to `new_layout`. Source mask covers the writes that
+/// can happen in `old_layout` and shall be made available, destination mask
+/// covers the accesses that can happen in `new_layout`.
+pub fn access_masks(
+    old_layout: ImageLayout,
+    new_layout: ImageLayout,
+) -> (AccessFlags, AccessFlags) {
+    let src = match old_layout {
+        ImageLayout::Undefined => AccessFlags::none(),
+        ImageLayout::Preinitialized => AccessFlags {
+            host_write: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::General => AccessFlags {
+            memory_write: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::ColorAttachmentOptimal => AccessFlags {
+            color_attachment_write: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::DepthStencilAttachmentOptimal => AccessFlags {
+            depth_stencil_attachment_write: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::TransferDstOptimal => AccessFlags {
+            transfer_write: true,
+            ..AccessFlags::none()
+        },
+        // read-only layouts, nothing to make available.
+        ImageLayout::DepthStencilReadOnlyOptimal
+        | ImageLayout::ShaderReadOnlyOptimal
+        | ImageLayout::TransferSrcOptimal
+        | ImageLayout::PresentSrc => AccessFlags::none(),
+    };
+
+    let dst = match new_layout {
+        ImageLayout::General => AccessFlags {
+            memory_read: true,
+            memory_write: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::ColorAttachmentOptimal => AccessFlags {
+            color_attachment_read: true,
+            color_attachment_write: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::DepthStencilAttachmentOptimal => AccessFlags {
+            depth_stencil_attachment_read: true,
+            depth_stencil_attachment_write: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::DepthStencilReadOnlyOptimal => AccessFlags {
+            depth_stencil_attachment_read: true,
+            shader_read: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::ShaderReadOnlyOptimal => AccessFlags {
+            shader_read: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::TransferSrcOptimal => AccessFlags {
+            transfer_read: true,
+            ..AccessFlags::none()
+        },
+        ImageLayout::TransferDstOptimal => AccessFlags {
+            transfer_write: true,
+            ..AccessFlags::none()
+        },
+        // presentation engine does its own synchronization via semaphores, and
+        // image cannot be transitioned into undefined/preinitialized layouts.
+        ImageLayout::PresentSrc
+        | ImageLayout::Undefined
+        | ImageLayout::Preinitialized => AccessFlags::none(),
+    };
+
+    (src, dst)
+}
+
+/// Record a pipeline barrier transitioning all mip-levels and array-layers of
+/// `image` from `old_layout` to `new_layout`. Access masks are computed using
+/// [access_masks].
+///
+/// AutoCommandBufferBuilder tracks image layouts by itself, hence this is meant
+/// for command buffers recorded via [UnsafeCommandBufferBuilder].
+///
+/// # Safety
+///
+/// Caller must ensure that `image` is in `old_layout` when the barrier executes,
+/// and that `src_stage` and `dst_stage` are supported by the command buffer's
+/// queue family.
+///
+/// `src_stage` and `dst_stage` must also be compatible with the access masks
+/// returned by [access_masks], that is, every access in a mask must be supported
+/// by at least one stage in the corresponding stage mask. For example
+/// `ColorAttachmentOptimal` as `old_layout` needs `color_attachment_output` in
+/// `src_stage`, and `ShaderReadOnlyOptimal` as `new_layout` needs a shader stage,
+/// like `fragment_shader`, in `dst_stage`. vulkano debug-asserts this, hence a
+/// mismatch shall panic in debug builds.
+pub unsafe fn image_memory_barrier<I>(
+    cmd_buffer: &mut UnsafeCommandBufferBuilder,
+    image: &I,
+    old_layout: ImageLayout,
+    new_layout: ImageLayout,
+    src_stage: PipelineStages,
+    dst_stage: PipelineStages,
+) where
+    I: ?Sized + ImageAccess,
+{
+    let (src_access, dst_access) = access_masks(old_layout, new_layout);
+    let layers = image.dimensions().array_layers();
+    let mipmaps = image.mipmap_levels();
+
+    let mut barrier = UnsafeCommandBufferBuilderPipelineBarrier::new();
+    barrier.add_image_memory_barrier(
+        image,
+        0..mipmaps,
+        0..layers,
+        src_stage,
+        src_access,
+        dst_stage,
+        dst_access,
+        false,
+        None,
+        old_layout,
+        new_layout,
+    );
+    cmd_buffer.pipeline_barrier(&barrier);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_masks() {
+        let (src, dst) =
+            access_masks(ImageLayout::Undefined, ImageLayout::TransferDstOptimal);
+        assert_eq!(src, AccessFlags::none());
+        let ref_dst = AccessFlags {
+            transfer_write: true,
+            ..AccessFlags::none()
+        };
+        assert_eq!(dst, ref_dst);
+
+        let (src, dst) = access_masks(
+            ImageLayout::TransferDstOptimal,
+            ImageLayout::ShaderReadOnlyOptimal,
+        );
+        let ref_src = AccessFlags {
+            transfer_write: true,
+            ..AccessFlags::none()
+        };
+        let ref_dst = AccessFlags {
+            shader_read: true,
+            ..AccessFlags::none()
+        };
+        assert_eq!(src, ref_src);
+        assert_eq!(dst, ref_dst);
+
+        let (src, dst) = access_masks(ImageLayout::General, ImageLayout::PresentSrc);
+        let ref_src = AccessFlags {
+            memory_write: true,
+            ..AccessFlags::none()
+        };
+        assert_eq!(src, ref_src);
+        assert_eq!(dst, AccessFlags::none());
+    }
+}
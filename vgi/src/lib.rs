@@ -46,8 +46,10 @@ macro_rules! err_at {
     }};
 }
 
+pub mod commands;
 pub mod pp;
 pub mod registry;
+pub mod util;
 pub mod vulkan;
 
 /// Error variants that are returned by this package's API.
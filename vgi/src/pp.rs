@@ -13,6 +13,8 @@ use vulkano::{
 
 use std::fmt;
 
+use crate::vulkan::{PhysicalDeviceInfo, QueueCapability};
+
 #[macro_export]
 macro_rules! format_unwrap_or {
     ($val:expr, $tos:ident, $def:expr) => {
@@ -185,6 +187,54 @@ impl<'a> PrettyRow for QueueFamily<'a> {
     }
 }
 
+impl PrettyRow for PhysicalDeviceInfo {
+    fn to_format() -> prettytable::format::TableFormat {
+        *prettytable::format::consts::FORMAT_CLEAN
+    }
+
+    fn to_head() -> prettytable::Row {
+        row![
+            Fy => "Index", "Name", "VendorId", "Type", "ApiVersion", "DriverVersion",
+            "QueueFamilies"
+        ]
+    }
+
+    fn to_row(&self) -> prettytable::Row {
+        let qfs: Vec<String> = self
+            .queue_families
+            .iter()
+            .enumerate()
+            .map(|(id, caps)| {
+                let caps: Vec<&str> = caps
+                    .iter()
+                    .map(|cap| match cap {
+                        QueueCapability::Graphics => "graphics",
+                        QueueCapability::Compute => "compute",
+                        QueueCapability::Transfer => "transfer",
+                        QueueCapability::SparseBinding => "sparse",
+                    })
+                    .collect();
+                format!("{}:{}", id, caps.join("|"))
+            })
+            .collect();
+
+        let hex = |x: &u32| format!("{:#x}", x);
+        let ver = |(major, minor, patch): &(u32, u32, u32)| {
+            format!("{}.{}.{}", major, minor, patch)
+        };
+
+        row![
+            self.index,
+            format_unwrap_or!(self.device_name, tos, "-"),
+            format_unwrap_or!(self.vendor_id, hex, "-"),
+            format_unwrap_or!(self.device_type, tod, "-"),
+            format_unwrap_or!(self.api_version, ver, "-"),
+            format_unwrap_or!(self.driver_version, tos, "-"),
+            qfs.join(" ")
+        ]
+    }
+}
+
 // TODO: don't do this as table, there are way too many details.
 impl<'a> PrettyRow for PhysicalDevice<'a> {
     fn to_format() -> prettytable::format::TableFormat {
@@ -0,0 +1,245 @@
+//! Utility types that are not tied to vulkan.
+
+use std::time::{Duration, Instant};
+
+pub mod noise;
+
+/// Measure elapsed time for labelled sections of user code, like the sections
+/// within an `on_redraw_requested` handler.
+///
+/// Sections can be nested, every call to [Stopwatch::start] pushes a new entry
+/// and every call to [Stopwatch::stop] pops the inner-most entry and records its
+/// elapsed time. Recorded timings are accumulated until [Stopwatch::reset].
+#[derive(Clone, Debug, Default)]
+pub struct Stopwatch {
+    stack: Vec<(String, Instant)>,
+    records: Vec<(String, Duration)>,
+}
+
+impl Stopwatch {
+    pub fn new() -> Self {
+        Stopwatch::default()
+    }
+
+    /// Start timing a new section identified by `label`.
+    pub fn start(&mut self, label: &str) {
+        self.stack.push((label.to_string(), Instant::now()))
+    }
+
+    /// Stop timing the inner-most section, record and return its elapsed time.
+    /// Return zero duration if there is no section to stop.
+    pub fn stop(&mut self) -> Duration {
+        match self.stack.pop() {
+            Some((label, start)) => {
+                let elapsed = start.elapsed();
+                self.records.push((label, elapsed));
+                elapsed
+            }
+            None => Duration::default(),
+        }
+    }
+
+    /// Return recorded timings, in the order sections were stopped.
+    pub fn report(&self) -> Vec<(String, Duration)> {
+        self.records.clone()
+    }
+
+    /// Clear recorded timings and abandon sections that are not yet stopped.
+    pub fn reset(&mut self) {
+        self.stack.clear();
+        self.records.clear();
+    }
+
+    /// Return the average elapsed time for `label`, over its last `n_frames`
+    /// recordings. Return None if `label` was never recorded or `n_frames` is
+    /// zero.
+    pub fn avg_over(&self, label: &str, n_frames: usize) -> Option<Duration> {
+        let items: Vec<Duration> = self
+            .records
+            .iter()
+            .rev()
+            .filter(|(l, _)| l == label)
+            .map(|(_, d)| *d)
+            .take(n_frames)
+            .collect();
+
+        match items.len() {
+            0 => None,
+            n => Some(items.into_iter().sum::<Duration>() / (n as u32)),
+        }
+    }
+}
+
+/// Axis aligned bounding box in 2D.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AABB {
+    pub min_x: f32,
+    pub min_y: f32,
+    pub max_x: f32,
+    pub max_y: f32,
+}
+
+impl AABB {
+    /// Return true if `self` and `other` overlap, boxes that share an edge are
+    /// treated as overlapping.
+    pub fn intersects(&self, other: &AABB) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    /// Return true if point (`x`, `y`) falls within the box, including its edges.
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        x >= self.min_x && x <= self.max_x && y >= self.min_y && y <= self.max_y
+    }
+
+    /// Return the smallest box enclosing both `self` and `other`.
+    pub fn union(&self, other: &AABB) -> AABB {
+        AABB {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+}
+
+/// Return index pairs `(i, j)`, `i < j`, of intersecting boxes in `aabbs`,
+/// sorted in ascending order. Uses sweep-and-prune along the X axis, boxes are
+/// sorted by `min_x` and only boxes overlapping along X are tested.
+pub fn broad_phase_pairs(aabbs: &[AABB]) -> Vec<(usize, usize)> {
+    use std::cmp::Ordering;
+
+    let mut order: Vec<usize> = (0..aabbs.len()).collect();
+    order.sort_by(|a, b| {
+        let (a, b) = (aabbs[*a].min_x, aabbs[*b].min_x);
+        a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+    });
+
+    let mut pairs = vec![];
+    let mut active: Vec<usize> = vec![];
+    for i in order.into_iter() {
+        let bx = &aabbs[i];
+        active.retain(|j| aabbs[*j].max_x >= bx.min_x);
+        for j in active.iter() {
+            if bx.intersects(&aabbs[*j]) {
+                pairs.push((i.min(*j), i.max(*j)));
+            }
+        }
+        active.push(i);
+    }
+
+    pairs.sort_unstable();
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopwatch() {
+        let mut sw = Stopwatch::new();
+        assert_eq!(sw.stop(), Duration::default());
+
+        sw.start("frame");
+        sw.start("draw");
+        std::thread::sleep(Duration::from_millis(2));
+        let draw = sw.stop();
+        let frame = sw.stop();
+        assert!(draw >= Duration::from_millis(2), "{:?}", draw);
+        assert!(frame >= draw, "{:?} {:?}", frame, draw);
+
+        let report = sw.report();
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0], ("draw".to_string(), draw));
+        assert_eq!(report[1], ("frame".to_string(), frame));
+
+        sw.start("frame");
+        sw.reset();
+        assert!(sw.report().is_empty());
+        assert_eq!(sw.stop(), Duration::default());
+    }
+
+    #[test]
+    fn test_stopwatch_avg_over() {
+        let mut sw = Stopwatch::new();
+        for ms in [10, 20, 30, 40].iter() {
+            sw.records
+                .push(("draw".to_string(), Duration::from_millis(*ms)));
+            sw.records
+                .push(("frame".to_string(), Duration::from_millis(100)));
+        }
+
+        assert_eq!(sw.avg_over("draw", 2), Some(Duration::from_millis(35)));
+        assert_eq!(sw.avg_over("draw", 4), Some(Duration::from_millis(25)));
+        assert_eq!(sw.avg_over("draw", 10), Some(Duration::from_millis(25)));
+        assert_eq!(sw.avg_over("frame", 3), Some(Duration::from_millis(100)));
+        assert_eq!(sw.avg_over("draw", 0), None);
+        assert_eq!(sw.avg_over("swap", 2), None);
+    }
+
+    fn aabb(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> AABB {
+        AABB {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+
+    #[test]
+    fn test_aabb() {
+        let a = aabb(0.0, 0.0, 10.0, 10.0);
+        assert!(a.intersects(&aabb(5.0, 5.0, 15.0, 15.0)));
+        assert!(a.intersects(&aabb(2.0, 2.0, 4.0, 4.0)));
+        assert!(a.intersects(&aabb(10.0, 0.0, 20.0, 10.0)));
+        assert!(!a.intersects(&aabb(11.0, 0.0, 20.0, 10.0)));
+        assert!(!a.intersects(&aabb(0.0, -5.0, 10.0, -1.0)));
+
+        assert!(a.contains_point(0.0, 10.0));
+        assert!(a.contains_point(5.0, 5.0));
+        assert!(!a.contains_point(10.5, 5.0));
+        assert!(!a.contains_point(5.0, -0.5));
+
+        let b = aabb(-5.0, 2.0, 3.0, 12.0);
+        assert_eq!(a.union(&b), aabb(-5.0, 0.0, 10.0, 12.0));
+        assert_eq!(b.union(&a), a.union(&b));
+    }
+
+    #[test]
+    fn test_broad_phase_pairs() {
+        assert!(broad_phase_pairs(&[]).is_empty());
+
+        let aabbs = [
+            aabb(0.0, 0.0, 10.0, 10.0),
+            aabb(20.0, 0.0, 30.0, 10.0),
+            aabb(5.0, 5.0, 25.0, 8.0),
+            aabb(5.0, 20.0, 25.0, 30.0),
+        ];
+        assert_eq!(broad_phase_pairs(&aabbs), vec![(0, 2), (1, 2)]);
+
+        // compare with brute force over random boxes.
+        let mut rng = noise::Xorshift64::new(0x1234);
+        let mut rand = |n: u64| (rng.next_u64() % n) as f32;
+        let aabbs: Vec<AABB> = (0..200)
+            .map(|_| {
+                let (x, y) = (rand(1000), rand(1000));
+                aabb(x, y, x + rand(50), y + rand(50))
+            })
+            .collect();
+
+        let mut refs = vec![];
+        for i in 0..aabbs.len() {
+            for j in (i + 1)..aabbs.len() {
+                if aabbs[i].intersects(&aabbs[j]) {
+                    refs.push((i, j))
+                }
+            }
+        }
+        assert!(!refs.is_empty());
+        assert_eq!(broad_phase_pairs(&aabbs), refs);
+    }
+}
@@ -0,0 +1,261 @@
+//! 2D simplex noise, computed on the CPU, for procedural content.
+//!
+//! Free functions [simplex2d] and [simplex2d_fbm] use Ken Perlin's reference
+//! permutation table. Use [Noise::new] for a permutation table seeded from a
+//! user supplied `u64`, results are reproducible for the same seed.
+
+// skewing and unskewing factors for two dimensions.
+const F2: f32 = 0.366_025_42; // 0.5 * (sqrt(3) - 1)
+const G2: f32 = 0.211_324_87; // (3 - sqrt(3)) / 6
+
+const GRAD3: [[f32; 2]; 12] = [
+    [1.0, 1.0],
+    [-1.0, 1.0],
+    [1.0, -1.0],
+    [-1.0, -1.0],
+    [1.0, 0.0],
+    [-1.0, 0.0],
+    [1.0, 0.0],
+    [-1.0, 0.0],
+    [0.0, 1.0],
+    [0.0, -1.0],
+    [0.0, 1.0],
+    [0.0, -1.0],
+];
+
+#[rustfmt::skip]
+const PERM: [u8; 256] = [
+    151, 160, 137, 91, 90, 15, 131, 13, 201, 95, 96, 53, 194, 233, 7, 225,
+    140, 36, 103, 30, 69, 142, 8, 99, 37, 240, 21, 10, 23, 190, 6, 148,
+    247, 120, 234, 75, 0, 26, 197, 62, 94, 252, 219, 203, 117, 35, 11, 32,
+    57, 177, 33, 88, 237, 149, 56, 87, 174, 20, 125, 136, 171, 168, 68, 175,
+    74, 165, 71, 134, 139, 48, 27, 166, 77, 146, 158, 231, 83, 111, 229, 122,
+    60, 211, 133, 230, 220, 105, 92, 41, 55, 46, 245, 40, 244, 102, 143, 54,
+    65, 25, 63, 161, 1, 216, 80, 73, 209, 76, 132, 187, 208, 89, 18, 169,
+    200, 196, 135, 130, 116, 188, 159, 86, 164, 100, 109, 198, 173, 186, 3, 64,
+    52, 217, 226, 250, 124, 123, 5, 202, 38, 147, 118, 126, 255, 82, 85, 212,
+    207, 206, 59, 227, 47, 16, 58, 17, 182, 189, 28, 42, 223, 183, 170, 213,
+    119, 248, 152, 2, 44, 154, 163, 70, 221, 153, 101, 155, 167, 43, 172, 9,
+    129, 22, 39, 253, 19, 98, 108, 110, 79, 113, 224, 232, 178, 185, 112, 104,
+    218, 246, 97, 228, 251, 34, 242, 193, 238, 210, 144, 12, 191, 179, 162, 241,
+    81, 51, 145, 235, 249, 14, 239, 107, 49, 192, 214, 31, 181, 199, 106, 157,
+    184, 84, 204, 176, 115, 121, 50, 45, 127, 4, 150, 254, 138, 236, 205, 93,
+    222, 114, 67, 29, 24, 72, 243, 141, 128, 195, 78, 66, 215, 61, 156, 180,
+];
+
+const PERLIN: Noise = Noise { perm: PERM };
+
+/// Return 2D simplex noise at (`x`, `y`), in the range `[-1, 1]`.
+pub fn simplex2d(x: f32, y: f32) -> f32 {
+    PERLIN.simplex2d(x, y)
+}
+
+/// Return fractal noise at (`x`, `y`), by summing `octaves` of [simplex2d],
+/// refer to [Noise::simplex2d_fbm].
+pub fn simplex2d_fbm(
+    x: f32,
+    y: f32,
+    octaves: u32,
+    persistence: f32,
+    lacunarity: f32,
+) -> f32 {
+    PERLIN.simplex2d_fbm(x, y, octaves, persistence, lacunarity)
+}
+
+/// Xorshift64 pseudo random number generator, refer to
+/// <https://www.jstatsoft.org/article/view/v008i14>.
+#[derive(Clone, Debug)]
+pub struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    /// Create a new generator from `seed`. Xorshift cannot start from a zero
+    /// state, hence a zero `seed` is replaced with a fixed non-zero seed.
+    pub fn new(seed: u64) -> Xorshift64 {
+        let state = match seed {
+            0 => 0x9E37_79B9_7F4A_7C15,
+            seed => seed,
+        };
+        Xorshift64 { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+}
+
+/// Simplex noise generator, with its own permutation table.
+#[derive(Clone)]
+pub struct Noise {
+    perm: [u8; 256],
+}
+
+impl Default for Noise {
+    fn default() -> Noise {
+        PERLIN.clone()
+    }
+}
+
+impl Noise {
+    /// Create a noise generator, its permutation table is shuffled using
+    /// [Xorshift64] seeded with `seed`.
+    pub fn new(seed: u64) -> Noise {
+        let mut perm = [0_u8; 256];
+        perm.iter_mut().enumerate().for_each(|(i, p)| *p = i as u8);
+
+        let mut rng = Xorshift64::new(seed);
+        for i in (1..perm.len()).rev() {
+            let j = (rng.next_u64() % ((i as u64) + 1)) as usize;
+            perm.swap(i, j);
+        }
+
+        Noise { perm }
+    }
+
+    /// Return 2D simplex noise at (`x`, `y`), in the range `[-1, 1]`.
+    pub fn simplex2d(&self, x: f32, y: f32) -> f32 {
+        // skew the input space to find the simplex cell.
+        let s = (x + y) * F2;
+        let i = (x + s).floor();
+        let j = (y + s).floor();
+
+        // unskew the cell origin back to (x, y) space.
+        let t = (i + j) * G2;
+        let (x0, y0) = (x - (i - t), y - (j - t));
+
+        // offsets for the middle corner of the simplex.
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+
+        let (x1, y1) = (x0 - (i1 as f32) + G2, y0 - (j1 as f32) + G2);
+        let (x2, y2) = (x0 - 1.0 + 2.0 * G2, y0 - 1.0 + 2.0 * G2);
+
+        let ii = ((i as i32) & 255) as usize;
+        let jj = ((j as i32) & 255) as usize;
+
+        let n0 = self.corner(ii, jj, x0, y0);
+        let n1 = self.corner(ii + i1, jj + j1, x1, y1);
+        let n2 = self.corner(ii + 1, jj + 1, x2, y2);
+
+        // scale the result to [-1, 1].
+        70.0 * (n0 + n1 + n2)
+    }
+
+    /// Return fractal noise at (`x`, `y`), by summing `octaves` of
+    /// [Noise::simplex2d]. Each octave scales the frequency by `lacunarity` and
+    /// the amplitude by `persistence`. Result is normalized to `[-1, 1]`, and
+    /// zero `octaves` shall return `0.0`.
+    pub fn simplex2d_fbm(
+        &self,
+        x: f32,
+        y: f32,
+        octaves: u32,
+        persistence: f32,
+        lacunarity: f32,
+    ) -> f32 {
+        let (mut total, mut amplitude, mut frequency, mut max) = (0.0, 1.0, 1.0, 0.0);
+        for _ in 0..octaves {
+            total += amplitude * self.simplex2d(x * frequency, y * frequency);
+            max += amplitude;
+            amplitude *= persistence;
+            frequency *= lacunarity;
+        }
+
+        if max > 0.0 {
+            total / max
+        } else {
+            0.0
+        }
+    }
+
+    fn corner(&self, i: usize, j: usize, x: f32, y: f32) -> f32 {
+        let t = 0.5 - x * x - y * y;
+        if t < 0.0 {
+            0.0
+        } else {
+            let k = (self.perm[(i + self.perm[j & 255] as usize) & 255] % 12) as usize;
+            let g = GRAD3[k];
+            t.powi(4) * (g[0] * x + g[1] * y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> impl Iterator<Item = (f32, f32)> {
+        (-50..50).flat_map(|i| (-50..50).map(move |j| (i as f32 * 0.37, j as f32 * 0.53)))
+    }
+
+    #[test]
+    fn test_perm() {
+        for perm in [PERM, Noise::new(0).perm, Noise::new(42).perm].iter() {
+            let mut items = perm.to_vec();
+            items.sort_unstable();
+            assert_eq!(items, (0..=255).collect::<Vec<u8>>());
+        }
+    }
+
+    #[test]
+    fn test_xorshift64() {
+        let a: Vec<u64> = {
+            let mut rng = Xorshift64::new(42);
+            (0..10).map(|_| rng.next_u64()).collect()
+        };
+        let b: Vec<u64> = {
+            let mut rng = Xorshift64::new(42);
+            (0..10).map(|_| rng.next_u64()).collect()
+        };
+        assert_eq!(a, b);
+        assert!(a.iter().all(|x| *x != 0));
+        assert_ne!(Xorshift64::new(0).next_u64(), 0);
+    }
+
+    #[test]
+    fn test_simplex2d_range() {
+        let noise = Noise::new(1234);
+        let (mut min, mut max) = (f32::MAX, f32::MIN);
+        for (x, y) in grid() {
+            for val in [simplex2d(x, y), noise.simplex2d(x, y)].iter() {
+                assert!((-1.0..=1.0).contains(val), "{}", val);
+                min = min.min(*val);
+                max = max.max(*val);
+            }
+        }
+        // noise shall not be flat.
+        assert!(min < -0.5 && max > 0.5, "{} {}", min, max);
+    }
+
+    #[test]
+    fn test_simplex2d_fbm_range() {
+        let noise = Noise::new(1234);
+        for (x, y) in grid() {
+            let val = noise.simplex2d_fbm(x, y, 5, 0.5, 2.0);
+            assert!((-1.0..=1.0).contains(&val), "{}", val);
+        }
+        assert_eq!(simplex2d_fbm(1.5, 2.5, 0, 0.5, 2.0), 0.0);
+        assert_eq!(simplex2d_fbm(1.5, 2.5, 1, 0.5, 2.0), simplex2d(1.5, 2.5));
+    }
+
+    #[test]
+    fn test_simplex2d_determinism() {
+        let (a, b, c) = (Noise::new(7), Noise::new(7), Noise::new(8));
+        let mut differs = false;
+        for (x, y) in grid() {
+            assert_eq!(a.simplex2d(x, y), b.simplex2d(x, y));
+            assert_eq!(
+                a.simplex2d_fbm(x, y, 4, 0.5, 2.0),
+                b.simplex2d_fbm(x, y, 4, 0.5, 2.0)
+            );
+            assert_eq!(simplex2d(x, y), Noise::default().simplex2d(x, y));
+            differs = differs || a.simplex2d(x, y) != c.simplex2d(x, y);
+        }
+        assert!(differs, "different seeds shall give different noise");
+    }
+}
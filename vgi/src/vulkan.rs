@@ -1,12 +1,23 @@
 use vulkano::{
+    buffer::{BufferUsage, DeviceLocalBuffer},
+    descriptor::{descriptor::ShaderStages, DescriptorSet},
     device::{DeviceExtensions, Features, Properties, Queue},
     format::{Format, FormatProperties},
     image::{
-        ImageCreateFlags, ImageFormatProperties, ImageTiling, ImageType, ImageUsage,
+        ImageCreateFlags, ImageFormatProperties, ImageLayout, ImageTiling, ImageType,
+        ImageUsage, SampleCounts,
     },
     instance::{
         ApplicationInfo, Instance, InstanceExtensions, LayerProperties, MemoryHeap,
-        MemoryType, PhysicalDevice, QueueFamily, Version,
+        MemoryType, PhysicalDevice, PhysicalDeviceType, QueueFamily, ResolveModes,
+        SubgroupFeatures, Version,
+    },
+    pipeline::{
+        shader::{EntryPointAbstract, SpecializationConstants},
+        ComputePipeline,
+    },
+    render_pass::{
+        AttachmentDesc, LoadOp, RenderPass, RenderPassDesc, StoreOp, SubpassDesc,
     },
 };
 
@@ -14,12 +25,20 @@ use std::sync::Arc;
 
 use crate::{Error, Result};
 
+/// Validation layers in the order of preference, refer to
+/// [Builder::with_validation_layers].
+const VALIDATION_LAYERS: [&str; 2] = [
+    "VK_LAYER_KHRONOS_validation",
+    "VK_LAYER_LUNARG_standard_validation",
+];
+
 pub fn layers() -> Result<Vec<LayerProperties>> {
     Ok(err_at!(Vk, vulkano::instance::layers_list())?.collect())
 }
 
 /// Maps to VkQueueFlagBits.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QueueCapability {
     Graphics,
     Compute,
@@ -97,11 +116,87 @@ pub fn api_version() -> Result<Version> {
     err_at!(Vk, funcptrs.api_version())
 }
 
+/// Maps to VkPhysicalDeviceType.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeviceType {
+    Other,
+    IntegratedGpu,
+    DiscreteGpu,
+    VirtualGpu,
+    Cpu,
+}
+
+impl From<PhysicalDeviceType> for DeviceType {
+    fn from(val: PhysicalDeviceType) -> Self {
+        match val {
+            PhysicalDeviceType::Other => DeviceType::Other,
+            PhysicalDeviceType::IntegratedGpu => DeviceType::IntegratedGpu,
+            PhysicalDeviceType::DiscreteGpu => DeviceType::DiscreteGpu,
+            PhysicalDeviceType::VirtualGpu => DeviceType::VirtualGpu,
+            PhysicalDeviceType::Cpu => DeviceType::Cpu,
+        }
+    }
+}
+
+/// Summary of a physical-device, refer to [Vulkan::list_physical_devices]. Enable
+/// `serde` feature to make this type serializable. Properties not reported by the
+/// device are left as None.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhysicalDeviceInfo {
+    pub index: usize,
+    pub device_name: Option<String>,
+    pub vendor_id: Option<u32>,
+    pub device_type: Option<DeviceType>,
+    /// Vulkan version supported by the device, as (major, minor, patch).
+    pub api_version: Option<(u32, u32, u32)>,
+    pub driver_version: Option<u32>,
+    /// Capabilities supported by each queue-family, indexed by queue-family id.
+    pub queue_families: Vec<Vec<QueueCapability>>,
+}
+
+impl<'a> From<PhysicalDevice<'a>> for PhysicalDeviceInfo {
+    fn from(pd: PhysicalDevice<'a>) -> Self {
+        let props = pd.properties();
+        let queue_families = pd
+            .queue_families()
+            .map(|qf| {
+                let mut caps = vec![];
+                if qf.supports_graphics() {
+                    caps.push(QueueCapability::Graphics)
+                }
+                if qf.supports_compute() {
+                    caps.push(QueueCapability::Compute)
+                }
+                if qf.explicitly_supports_transfers() {
+                    caps.push(QueueCapability::Transfer)
+                }
+                if qf.supports_sparse_binding() {
+                    caps.push(QueueCapability::SparseBinding)
+                }
+                caps
+            })
+            .collect();
+
+        PhysicalDeviceInfo {
+            index: pd.index(),
+            device_name: props.device_name.clone(),
+            vendor_id: props.vendor_id,
+            device_type: props.device_type.map(DeviceType::from),
+            api_version: props.api_version.map(|v| (v.major, v.minor, v.patch)),
+            driver_version: props.driver_version,
+            queue_families,
+        }
+    }
+}
+
 pub struct Builder<'a> {
     // instance attributes
     app_info: ApplicationInfo<'a>,
     version: Version,
     layers: Vec<String>,
+    validation: bool,
     iextns: InstanceExtensions,
     // device attributes
     device_id: usize,
@@ -124,6 +219,7 @@ impl<'a> Builder<'a> {
             version: api_version()?,
             iextns: InstanceExtensions::none(),
             layers: Vec::default(),
+            validation: false,
             // device attributes
             device_id: 0,
             queue_infos: vec![QueueCreateInfo::default()],
@@ -155,6 +251,7 @@ impl<'a> Builder<'a> {
             version,
             iextns: InstanceExtensions::none(),
             layers: Vec::default(),
+            validation: false,
             // device attributes
             device_id: 0,
             queue_infos: vec![QueueCreateInfo::default()],
@@ -179,6 +276,16 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Enable khronos validation layer, or the older lunarg standard validation
+    /// layer, if it is installed. This is a no-op in release builds, and when no
+    /// validation layer is available. Validation layer is added to the list of
+    /// layers while building, hence it can be called before or after
+    /// [Builder::with_layers].
+    pub fn with_validation_layers(mut self) -> Self {
+        self.validation = true;
+        self
+    }
+
     /// List of instance-extensions to enable while creating vulkan-instance. If
     /// `extensions` is None, then all supported core extensions shall be enabled.
     ///
@@ -227,6 +334,17 @@ impl<'a> Builder<'a> {
         self
     }
 
+    // list of layers to enable, including the validation layer if requested.
+    fn to_layers(&self) -> Result<Vec<String>> {
+        if self.validation && cfg!(debug_assertions) {
+            let available: Vec<String> =
+                layers()?.iter().map(|l| l.name().to_string()).collect();
+            Ok(add_validation_layer(self.layers.clone(), &available))
+        } else {
+            Ok(self.layers.clone())
+        }
+    }
+
     /// Finally call build, to obtain the [Vulkan] object. There are two variant
     /// of build, one to build for a platform dependant surface for which use
     /// `build_for_surface` method and second to rendering into image buffer.
@@ -238,9 +356,10 @@ impl<'a> Builder<'a> {
         use winit::event_loop::EventLoop;
         use winit::window::WindowBuilder;
 
+        let names = self.to_layers()?;
         let instance = {
             let iextns = union_iextns(self.iextns.clone(), surface);
-            let layers = self.layers.iter().map(|s| s.as_str());
+            let layers = names.iter().map(|s| s.as_str());
             let res = Instance::new(Some(&self.app_info), self.version, &iextns, layers);
             Box::new(err_at!(Vk, res)?)
         };
@@ -252,7 +371,7 @@ impl<'a> Builder<'a> {
             PhysicalDevice::enumerate(inst).collect()
         };
         let pd = pds[self.device_id];
-        confirm_properties(&self, pd.properties().clone())?;
+        confirm_properties(&self.properties, pd.properties())?;
         let qfamilies: Vec<QueueFamily> = pd.queue_families().collect();
 
         let dextns = match self.dextns {
@@ -292,7 +411,7 @@ impl<'a> Builder<'a> {
 
         let layers = layers()?
             .into_iter()
-            .filter(|l| self.layers.contains(&l.name().to_string()))
+            .filter(|l| names.contains(&l.name().to_string()))
             .collect();
 
         let val = Vulkan {
@@ -322,9 +441,10 @@ impl<'a> Builder<'a> {
         use vulkano::device::Device;
         use vulkano::image::AttachmentImage;
 
+        let names = self.to_layers()?;
         let instance = {
             let iextns = self.iextns.clone();
-            let layers = self.layers.iter().map(|s| s.as_str());
+            let layers = names.iter().map(|s| s.as_str());
             let res = Instance::new(Some(&self.app_info), self.version, &iextns, layers);
             Box::new(err_at!(Vk, res)?)
         };
@@ -336,7 +456,7 @@ impl<'a> Builder<'a> {
             PhysicalDevice::enumerate(inst).collect()
         };
         let pd = pds[self.device_id];
-        confirm_properties(&self, pd.properties().clone())?;
+        confirm_properties(&self.properties, pd.properties())?;
         let qfamilies: Vec<QueueFamily> = pd.queue_families().collect();
 
         let dextns = match self.dextns {
@@ -369,7 +489,7 @@ impl<'a> Builder<'a> {
 
         let layers = layers()?
             .into_iter()
-            .filter(|l| self.layers.contains(&l.name().to_string()))
+            .filter(|l| names.contains(&l.name().to_string()))
             .collect();
 
         let val = Vulkan {
@@ -593,6 +713,238 @@ where
     pub fn to_image(&self) -> Arc<vulkano::image::AttachmentImage> {
         self.target.to_image()
     }
+
+    /// Create a render-pass with a single color attachment and a single subpass
+    /// writing into it. Typically `format` is same as the swapchain's format or
+    /// the format of the target image.
+    pub fn create_render_pass(
+        &self,
+        format: Format,
+        load_op: LoadOp,
+        store_op: StoreOp,
+    ) -> Result<Arc<RenderPass>> {
+        let attachments = vec![color_attachment_desc(format, load_op, store_op)];
+        let subpasses = vec![SubpassDesc {
+            color_attachments: vec![(0, ImageLayout::ColorAttachmentOptimal)],
+            depth_stencil: None,
+            input_attachments: vec![],
+            resolve_attachments: vec![],
+            preserve_attachments: vec![],
+        }];
+        let desc = RenderPassDesc::new(attachments, subpasses, vec![]);
+
+        Ok(Arc::new(err_at!(
+            Vk,
+            RenderPass::new(self.to_device(), desc)
+        )?))
+    }
+
+    /// Similar to [Vulkan::create_render_pass], but adds a depth attachment, of
+    /// `depth_format`, to the subpass. Depth attachment is always cleared on load
+    /// and its content discarded after the render-pass. Stencil aspect, if
+    /// `depth_format` has one, is treated the same way. Fails with `Invalid` error
+    /// if `depth_format` has neither depth nor stencil aspect.
+    pub fn create_depth_render_pass(
+        &self,
+        format: Format,
+        depth_format: Format,
+        load_op: LoadOp,
+        store_op: StoreOp,
+    ) -> Result<Arc<RenderPass>> {
+        use vulkano::format::FormatTy;
+
+        let stencil_load = match depth_format.ty() {
+            FormatTy::Stencil | FormatTy::DepthStencil => LoadOp::Clear,
+            FormatTy::Depth => LoadOp::DontCare,
+            _ => err_at!(Invalid, msg: "{:?} not a depth/stencil format", depth_format)?,
+        };
+        let attachments = vec![
+            color_attachment_desc(format, load_op, store_op),
+            AttachmentDesc {
+                format: depth_format,
+                samples: 1,
+                load: LoadOp::Clear,
+                store: StoreOp::DontCare,
+                stencil_load,
+                stencil_store: StoreOp::DontCare,
+                initial_layout: ImageLayout::Undefined,
+                final_layout: ImageLayout::DepthStencilAttachmentOptimal,
+            },
+        ];
+        let subpasses = vec![SubpassDesc {
+            color_attachments: vec![(0, ImageLayout::ColorAttachmentOptimal)],
+            depth_stencil: Some((1, ImageLayout::DepthStencilAttachmentOptimal)),
+            input_attachments: vec![],
+            resolve_attachments: vec![],
+            preserve_attachments: vec![],
+        }];
+        let desc = RenderPassDesc::new(attachments, subpasses, vec![]);
+
+        Ok(Arc::new(err_at!(
+            Vk,
+            RenderPass::new(self.to_device(), desc)
+        )?))
+    }
+
+    /// Create a device local storage-buffer of `size_bytes`, accessible to all
+    /// active queue-families. Buffer is never host visible, if `transferable` is
+    /// true it can be used as source and destination for transfers, so that its
+    /// content can be uploaded from, and downloaded to, host memory via staging
+    /// buffers, refer to [Vulkan::read_storage_buffer].
+    pub fn create_storage_buffer(
+        &self,
+        size_bytes: u64,
+        transferable: bool,
+    ) -> Result<Arc<DeviceLocalBuffer<[u8]>>> {
+        let usage = BufferUsage {
+            storage_buffer: true,
+            transfer_source: transferable,
+            transfer_destination: transferable,
+            ..BufferUsage::none()
+        };
+        err_at!(
+            Vk,
+            DeviceLocalBuffer::array(
+                self.to_device(),
+                size_bytes,
+                usage,
+                self.device.active_queue_families(),
+            )
+        )
+    }
+
+    /// Read back the content of storage-buffer `buf`, created via
+    /// [Vulkan::create_storage_buffer] with `transferable`. Content is copied
+    /// into a staging buffer using `queue` and this call blocks until the copy
+    /// is complete. Fails with `Invalid` error if `buf` is not transferable.
+    pub fn read_storage_buffer(
+        &self,
+        buf: &Arc<DeviceLocalBuffer<[u8]>>,
+        queue: &Arc<Queue>,
+    ) -> Result<Vec<u8>> {
+        use vulkano::buffer::{BufferAccess, CpuAccessibleBuffer, TypedBufferAccess};
+        use vulkano::command_buffer::{
+            AutoCommandBufferBuilder, CommandBufferUsage, PrimaryCommandBuffer,
+        };
+        use vulkano::sync::GpuFuture;
+
+        if !buf.inner().buffer.usage_transfer_source() {
+            err_at!(Invalid, msg: "storage buffer not created as transferable")?
+        }
+
+        let staging = err_at!(
+            Vk,
+            CpuAccessibleBuffer::from_iter(
+                self.to_device(),
+                BufferUsage::transfer_destination(),
+                false,
+                (0..buf.len()).map(|_| 0_u8),
+            )
+        )?;
+
+        let mut builder = err_at!(
+            Vk,
+            AutoCommandBufferBuilder::primary(
+                self.to_device(),
+                queue.family(),
+                CommandBufferUsage::OneTimeSubmit,
+            )
+        )?;
+        err_at!(
+            Vk,
+            builder.copy_buffer(Arc::clone(buf), Arc::clone(&staging))
+        )?;
+        let command_buffer = err_at!(Vk, builder.build())?;
+
+        let future = err_at!(Vk, command_buffer.execute(Arc::clone(queue)))?;
+        let future = err_at!(Vk, future.then_signal_fence_and_flush())?;
+        err_at!(Vk, future.wait(None))?;
+
+        let data = err_at!(Vk, staging.read())?;
+        Ok(data.to_vec())
+    }
+
+    /// Create a compute pipeline for `shader` entry point. Entry point, along with
+    /// its descriptor-set layout, is typically generated by `vulkano_shaders::shader!`
+    /// macro, like `cs::Shader::load(device)?.main_entry_point()`. Pipeline layout
+    /// is derived from the entry point, while descriptor sets are left to the
+    /// caller, refer to `pipeline.layout().descriptor_set_layout(n)`.
+    pub fn create_compute_pipeline<Cs, Css>(
+        &self,
+        shader: &Cs,
+        spec_constants: &Css,
+    ) -> Result<Arc<ComputePipeline>>
+    where
+        Cs: EntryPointAbstract,
+        Css: SpecializationConstants,
+    {
+        let res = ComputePipeline::new(self.to_device(), shader, spec_constants, None);
+        Ok(Arc::new(err_at!(Vk, res)?))
+    }
+
+    /// Record `pipeline` and its descriptor `set` into a one-shot command buffer,
+    /// dispatch `groups` work-groups on `queue`, and wait for it to complete.
+    pub fn dispatch(
+        &self,
+        pipeline: &Arc<ComputePipeline>,
+        set: Arc<dyn DescriptorSet + Send + Sync>,
+        groups: [u32; 3],
+        queue: &Arc<Queue>,
+    ) -> Result<()> {
+        use vulkano::command_buffer::{
+            AutoCommandBufferBuilder, CommandBufferUsage, PrimaryCommandBuffer,
+        };
+        use vulkano::sync::GpuFuture;
+
+        let mut builder = err_at!(
+            Vk,
+            AutoCommandBufferBuilder::primary(
+                self.to_device(),
+                queue.family(),
+                CommandBufferUsage::OneTimeSubmit,
+            )
+        )?;
+        err_at!(
+            Vk,
+            builder.dispatch(groups, Arc::clone(pipeline), set, (), vec![])
+        )?;
+        let command_buffer = err_at!(Vk, builder.build())?;
+
+        let future = err_at!(Vk, command_buffer.execute(Arc::clone(queue)))?;
+        let future = err_at!(Vk, future.then_signal_fence_and_flush())?;
+        err_at!(Vk, future.wait(None))?;
+
+        Ok(())
+    }
+}
+
+fn color_attachment_desc(format: Format, load: LoadOp, store: StoreOp) -> AttachmentDesc {
+    // when the attachment is cleared or ignored on load, its previous content is
+    // irrelevant, otherwise it is expected in color-attachment layout.
+    let initial_layout = match load {
+        LoadOp::Load => ImageLayout::ColorAttachmentOptimal,
+        LoadOp::Clear | LoadOp::DontCare => ImageLayout::Undefined,
+    };
+
+    AttachmentDesc {
+        format,
+        samples: 1,
+        load,
+        store,
+        stencil_load: LoadOp::DontCare,
+        stencil_store: StoreOp::DontCare,
+        initial_layout,
+        final_layout: ImageLayout::ColorAttachmentOptimal,
+    }
+}
+
+impl<'a> Vulkan<'a> {
+    /// Return summary of all physical-devices available for `instance`.
+    pub fn list_physical_devices(instance: &Arc<Instance>) -> Vec<PhysicalDeviceInfo> {
+        PhysicalDevice::enumerate(instance)
+            .map(PhysicalDeviceInfo::from)
+            .collect()
+    }
 }
 
 impl<'a, T> Vulkan<'a, winit::window::Window, T> {
@@ -714,8 +1066,28 @@ impl<'a, T> Vulkan<'a, winit::window::Window, T> {
         Ok(())
     }
 
-    pub fn recreate_swapchain(&mut self, _info: SwapchainCreateInfo) {
-        todo!()
+    /// Recreate the swapchain with `new_dimensions`, typically after window resize
+    /// or after a present returning suboptimal. Rest of the parameters are carried
+    /// over from the existing swapchain. Fails if swapchain is yet to be created.
+    pub fn recreate_swapchain(&mut self, new_dimensions: [u32; 2]) -> Result<()> {
+        match &mut self.target {
+            Target::Surface {
+                swapchain: Some(swpc),
+                images,
+                ..
+            } => {
+                let res = swpc.recreate().dimensions(new_dimensions).build();
+                let (swapchain_n, images_n) = err_at!(Vk, res)?;
+                *swpc = swapchain_n;
+                *images = images_n;
+            }
+            Target::Surface { .. } => err_at!(Invalid, msg: "swapchain yet to be built")?,
+            Target::Bitmap { .. } => {
+                err_at!(Invalid, msg: "vulkan target not a surface")?
+            }
+        };
+
+        Ok(())
     }
 
     pub unsafe fn wait(&self) -> Result<()> {
@@ -723,6 +1095,19 @@ impl<'a, T> Vulkan<'a, winit::window::Window, T> {
     }
 }
 
+// Add the preferred validation layer among `available` layers to `layers`, unless
+// it is already there. No-op if none of the validation layers are available.
+fn add_validation_layer(mut layers: Vec<String>, available: &[String]) -> Vec<String> {
+    let layer = VALIDATION_LAYERS
+        .iter()
+        .find(|name| available.iter().any(|n| n == *name));
+    match layer {
+        Some(name) if !layers.iter().any(|l| l == name) => layers.push(name.to_string()),
+        _ => (),
+    }
+    layers
+}
+
 // TODO: why are we even doing this ? How can a device extension is enabled when a device
 // feature is not available.
 pub fn extensions_for_features(
@@ -747,1014 +1132,781 @@ pub fn extensions_for_features(
     extensions
 }
 
-// TODO: split this into properties, limits and more...
-fn confirm_properties(val: &Builder, props: Properties) -> Result<()> {
-    let p = val.properties.clone();
+/// Device limits and capabilities that can be compared with the requested value.
+trait Limit {
+    /// Device value is same or better than `other`, for limits that are maximums.
+    fn at_least(&self, other: &Self) -> bool;
 
-    if let Some(_val) = p.active_compute_unit_count {
-        todo!()
-    }
-    if let Some(_val) = p.advanced_blend_all_operations {
-        todo!()
-    }
-    if let Some(_val) = p.advanced_blend_correlated_overlap {
-        todo!()
-    }
-    if let Some(_val) = p.advanced_blend_independent_blend {
-        todo!()
-    }
-    if let Some(_val) = p.advanced_blend_max_color_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.advanced_blend_non_premultiplied_dst_color {
-        todo!()
-    }
-    if let Some(_val) = p.advanced_blend_non_premultiplied_src_color {
-        todo!()
+    /// Device value is same or better than `other`, for limits that are minimums,
+    /// like alignments and granularities.
+    fn at_most(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_limit {
+    ($($t:ty),*) => {
+        $(
+            impl Limit for $t {
+                fn at_least(&self, other: &Self) -> bool {
+                    self >= other
+                }
+
+                fn at_most(&self, other: &Self) -> bool {
+                    self <= other
+                }
+            }
+        )*
+    };
+}
+
+impl_limit!(u32, u64, i32, f32, usize, Version);
+
+impl<T, const N: usize> Limit for [T; N]
+where
+    T: Limit,
+{
+    fn at_least(&self, other: &Self) -> bool {
+        self.iter().zip(other.iter()).all(|(a, b)| a.at_least(b))
     }
-    if let Some(_val) = p.allow_command_buffer_query_copies {
-        todo!()
+
+    fn at_most(&self, other: &Self) -> bool {
+        self.iter().zip(other.iter()).all(|(a, b)| a.at_most(b))
     }
-    if let Some(val) = p.api_version {
-        if props.api_version.unwrap().lt(&val) {
-            err_at!(Vk, msg: "api_version: {}", props.api_version.unwrap())?;
+}
+
+fn equals<T: PartialEq>(dev: &T, req: &T) -> bool {
+    dev == req
+}
+
+/// Device range `[min, max]` shall cover the requested range.
+fn covers<T: PartialOrd>(dev: &[T; 2], req: &[T; 2]) -> bool {
+    dev[0] <= req[0] && dev[1] >= req[1]
+}
+
+/// Bit-flag sets, like sample-counts and shader-stages, where device shall
+/// support all the requested flags.
+trait FlagSet {
+    /// Device flags are a superset of `other`.
+    fn contains(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_flag_set {
+    ($t:ty, $($field:ident),*) => {
+        impl FlagSet for $t {
+            fn contains(&self, other: &Self) -> bool {
+                $((self.$field || !other.$field))&&*
+            }
         }
-    }
-    if let Some(_val) = p.buffer_image_granularity {
-        todo!()
-    }
-    if let Some(_val) = p.compute_units_per_shader_array {
-        todo!()
-    }
-    if let Some(_val) = p.conformance_version {
-        todo!()
-    }
-    if let Some(_val) = p.conservative_point_and_line_rasterization {
-        todo!()
-    }
-    if let Some(_val) = p.conservative_rasterization_post_depth_coverage {
-        todo!()
-    }
-    if let Some(_val) = p.cooperative_matrix_supported_stages {
-        todo!()
-    }
-    if let Some(_val) = p.degenerate_lines_rasterized {
-        todo!()
-    }
-    if let Some(_val) = p.degenerate_triangles_rasterized {
-        todo!()
-    }
-    if let Some(_val) = p.denorm_behavior_independence {
-        todo!()
-    }
-    if let Some(_val) = p.device_id {
-        todo!()
-    }
-    if let Some(_val) = p.device_luid {
-        todo!()
-    }
-    if let Some(_val) = p.device_luid_valid {
-        todo!()
-    }
-    if let Some(_val) = p.device_name {
-        todo!()
-    }
-    if let Some(_val) = p.device_node_mask {
-        todo!()
-    }
-    if let Some(_val) = p.device_type {
-        todo!()
-    }
-    if let Some(_val) = p.device_uuid {
-        todo!()
-    }
-    if let Some(_val) = p.discrete_queue_priorities {
-        todo!()
-    }
-    if let Some(_val) = p.driver_id {
-        todo!()
-    }
-    if let Some(_val) = p.driver_info {
-        todo!()
-    }
-    if let Some(_val) = p.driver_name {
-        todo!()
-    }
-    if let Some(_val) = p.driver_uuid {
-        todo!()
-    }
-    if let Some(_val) = p.driver_version {
-        todo!()
-    }
-    if let Some(_val) = p.extra_primitive_overestimation_size_granularity {
-        todo!()
-    }
-    if let Some(_val) = p.filter_minmax_image_component_mapping {
-        todo!()
-    }
-    if let Some(_val) = p.filter_minmax_single_component_formats {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_density_invocations {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_shading_rate_non_trivial_combiner_ops {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_shading_rate_strict_multiply_combiner {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_shading_rate_with_conservative_rasterization {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_shading_rate_with_custom_sample_locations {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_shading_rate_with_fragment_shader_interlock {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_shading_rate_with_sample_mask {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_shading_rate_with_shader_depth_stencil_writes {
-        todo!()
-    }
-    if let Some(_val) = p.fragment_shading_rate_with_shader_sample_mask {
-        todo!()
-    }
-    if let Some(_val) = p.framebuffer_color_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.framebuffer_depth_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.framebuffer_integer_color_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.framebuffer_no_attachments_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.framebuffer_stencil_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.fully_covered_fragment_shader_input_variable {
-        todo!()
-    }
-    if let Some(_val) = p.independent_resolve {
-        todo!()
-    }
-    if let Some(_val) = p.independent_resolve_none {
-        todo!()
-    }
-    if let Some(_val) = p.layered_shading_rate_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.line_sub_pixel_precision_bits {
-        todo!()
-    }
-    if let Some(_val) = p.line_width_granularity {
-        todo!()
-    }
-    if let Some(_val) = p.line_width_range {
-        todo!()
-    }
-    if let Some(_val) = p.max_bound_descriptor_sets {
-        todo!()
-    }
-    if let Some(_val) = p.max_clip_distances {
-        todo!()
-    }
-    if let Some(_val) = p.max_color_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.max_combined_clip_and_cull_distances {
-        todo!()
-    }
-    if let Some(_val) = p.max_compute_shared_memory_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_compute_work_group_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_compute_work_group_invocations {
-        todo!()
-    }
-    if let Some(_val) = p.max_compute_work_group_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_compute_workgroup_subgroups {
-        todo!()
-    }
-    if let Some(_val) = p.max_cull_distances {
-        todo!()
-    }
-    if let Some(_val) = p.max_custom_border_color_samplers {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_acceleration_structures {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_inline_uniform_blocks {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_input_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_sampled_images {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_samplers {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_storage_buffers {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_storage_buffers_dynamic {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_storage_images {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_subsampled_samplers {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_uniform_buffers {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_uniform_buffers_dynamic {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_acceleration_structures {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_inline_uniform_blocks {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_input_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_sampled_images {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_samplers {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_storage_buffers {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_storage_buffers_dynamic {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_storage_images {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_uniform_buffers {
-        todo!()
-    }
-    if let Some(_val) = p.max_descriptor_set_update_after_bind_uniform_buffers_dynamic {
-        todo!()
-    }
-    if let Some(_val) = p.max_discard_rectangles {
-        todo!()
-    }
-    if let Some(_val) = p.max_draw_indexed_index_value {
-        todo!()
-    }
-    if let Some(_val) = p.max_draw_indirect_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_draw_mesh_tasks_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_extra_primitive_overestimation_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_combined_output_resources {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_density_texel_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_dual_src_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_input_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_output_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_shading_rate_attachment_texel_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_shading_rate_attachment_texel_size_aspect_ratio {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_shading_rate_coverage_samples {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_shading_rate_invocation_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_shading_rate_rasterization_samples {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_fragment_size_aspect_ratio {
-        todo!()
-    }
-    if let Some(_val) = p.max_framebuffer_height {
-        todo!()
-    }
-    if let Some(_val) = p.max_framebuffer_layers {
-        todo!()
-    }
-    if let Some(_val) = p.max_framebuffer_width {
-        todo!()
-    }
-    if let Some(_val) = p.max_geometry_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_geometry_input_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_geometry_output_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_geometry_output_vertices {
-        todo!()
-    }
-    if let Some(_val) = p.max_geometry_shader_invocations {
-        todo!()
-    }
-    if let Some(_val) = p.max_geometry_total_output_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_graphics_shader_group_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_image_array_layers {
-        todo!()
-    }
-    if let Some(_val) = p.max_image_dimension1_d {
-        todo!()
-    }
-    if let Some(_val) = p.max_image_dimension2_d {
-        todo!()
-    }
-    if let Some(_val) = p.max_image_dimension3_d {
-        todo!()
-    }
-    if let Some(_val) = p.max_image_dimension_cube {
-        todo!()
-    }
-    if let Some(_val) = p.max_indirect_commands_stream_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_indirect_commands_stream_stride {
-        todo!()
-    }
-    if let Some(_val) = p.max_indirect_commands_token_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_indirect_commands_token_offset {
-        todo!()
-    }
-    if let Some(_val) = p.max_indirect_sequence_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_inline_uniform_block_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_instance_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_interpolation_offset {
-        todo!()
-    }
-    if let Some(_val) = p.max_memory_allocation_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_memory_allocation_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_mesh_multiview_view_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_mesh_output_primitives {
-        todo!()
-    }
-    if let Some(_val) = p.max_mesh_output_vertices {
-        todo!()
-    }
-    if let Some(_val) = p.max_mesh_total_memory_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_mesh_work_group_invocations {
-        todo!()
-    }
-    if let Some(_val) = p.max_mesh_work_group_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_multiview_instance_index {
-        todo!()
-    }
-    if let Some(_val) = p.max_multiview_view_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_set_descriptors {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_acceleration_structures {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_inline_uniform_blocks {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_input_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_sampled_images {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_samplers {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_storage_buffers {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_storage_images {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_uniform_buffers {
-        todo!()
-    }
-    if let Some(_val) =
-        p.max_per_stage_descriptor_update_after_bind_acceleration_structures
-    {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_update_after_bind_inline_uniform_blocks
-    {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_update_after_bind_input_attachments {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_update_after_bind_sampled_images {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_update_after_bind_samplers {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_update_after_bind_storage_buffers {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_update_after_bind_storage_images {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_descriptor_update_after_bind_uniform_buffers {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_resources {
-        todo!()
-    }
-    if let Some(_val) = p.max_per_stage_update_after_bind_resources {
-        todo!()
-    }
-    if let Some(_val) = p.max_primitive_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_push_constants_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_push_descriptors {
-        todo!()
-    }
-    if let Some(_val) = p.max_ray_dispatch_invocation_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_ray_hit_attribute_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_ray_recursion_depth {
-        todo!()
-    }
-    if let Some(_val) = p.max_recursion_depth {
-        todo!()
-    }
-    if let Some(_val) = p.max_sample_location_grid_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_sample_mask_words {
-        todo!()
-    }
-    if let Some(_val) = p.max_sampler_allocation_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_sampler_anisotropy {
-        todo!()
-    }
-    if let Some(_val) = p.max_sampler_lod_bias {
-        todo!()
-    }
-    if let Some(_val) = p.max_sgpr_allocation {
-        todo!()
-    }
-    if let Some(_val) = p.max_shader_group_stride {
-        todo!()
-    }
-    if let Some(_val) = p.max_storage_buffer_range {
-        todo!()
-    }
-    if let Some(_val) = p.max_subgroup_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_subsampled_array_layers {
-        todo!()
-    }
-    if let Some(_val) = p.max_task_output_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_task_total_memory_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_task_work_group_invocations {
-        todo!()
-    }
-    if let Some(_val) = p.max_task_work_group_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_tessellation_control_per_patch_output_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_tessellation_control_per_vertex_input_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_tessellation_control_per_vertex_output_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_tessellation_control_total_output_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_tessellation_evaluation_input_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_tessellation_evaluation_output_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_tessellation_generation_level {
-        todo!()
-    }
-    if let Some(_val) = p.max_tessellation_patch_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_texel_buffer_elements {
-        todo!()
-    }
-    if let Some(_val) = p.max_texel_gather_offset {
-        todo!()
-    }
-    if let Some(_val) = p.max_texel_offset {
-        todo!()
-    }
-    if let Some(_val) = p.max_timeline_semaphore_value_difference {
-        todo!()
-    }
-    if let Some(_val) = p.max_transform_feedback_buffer_data_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_transform_feedback_buffer_data_stride {
-        todo!()
-    }
-    if let Some(_val) = p.max_transform_feedback_buffer_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_transform_feedback_buffers {
-        todo!()
-    }
-    if let Some(_val) = p.max_transform_feedback_stream_data_size {
-        todo!()
-    }
-    if let Some(_val) = p.max_transform_feedback_streams {
-        todo!()
-    }
-    if let Some(_val) = p.max_triangle_count {
-        todo!()
-    }
-    if let Some(_val) = p.max_uniform_buffer_range {
-        todo!()
-    }
-    if let Some(_val) = p.max_update_after_bind_descriptors_in_all_pools {
-        todo!()
-    }
-    if let Some(_val) = p.max_vertex_attrib_divisor {
-        todo!()
-    }
-    if let Some(_val) = p.max_vertex_input_attribute_offset {
-        todo!()
-    }
-    if let Some(_val) = p.max_vertex_input_attributes {
-        todo!()
-    }
-    if let Some(_val) = p.max_vertex_input_binding_stride {
-        todo!()
-    }
-    if let Some(_val) = p.max_vertex_input_bindings {
-        todo!()
-    }
-    if let Some(_val) = p.max_vertex_output_components {
-        todo!()
-    }
-    if let Some(_val) = p.max_vgpr_allocation {
-        todo!()
-    }
-    if let Some(_val) = p.max_viewport_dimensions {
-        todo!()
-    }
-    if let Some(_val) = p.max_viewports {
-        todo!()
-    }
-    if let Some(_val) = p.mesh_output_per_primitive_granularity {
-        todo!()
-    }
-    if let Some(_val) = p.mesh_output_per_vertex_granularity {
-        todo!()
-    }
-    if let Some(_val) = p.min_acceleration_structure_scratch_offset_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_fragment_density_texel_size {
-        todo!()
-    }
-    if let Some(_val) = p.min_fragment_shading_rate_attachment_texel_size {
-        todo!()
-    }
-    if let Some(_val) = p.min_imported_host_pointer_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_indirect_commands_buffer_offset_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_interpolation_offset {
-        todo!()
-    }
-    if let Some(_val) = p.min_memory_map_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_sequences_count_buffer_offset_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_sequences_index_buffer_offset_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_sgpr_allocation {
-        todo!()
-    }
-    if let Some(_val) = p.min_storage_buffer_offset_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_subgroup_size {
-        todo!()
-    }
-    if let Some(_val) = p.min_texel_buffer_offset_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_texel_gather_offset {
-        todo!()
-    }
-    if let Some(_val) = p.min_texel_offset {
-        todo!()
-    }
-    if let Some(_val) = p.min_uniform_buffer_offset_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_vertex_input_binding_stride_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.min_vgpr_allocation {
-        todo!()
-    }
-    if let Some(_val) = p.mipmap_precision_bits {
-        todo!()
-    }
-    if let Some(_val) = p.non_coherent_atom_size {
-        todo!()
-    }
-    if let Some(_val) = p.optimal_buffer_copy_offset_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.optimal_buffer_copy_row_pitch_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.pci_bus {
-        todo!()
-    }
-    if let Some(_val) = p.pci_device {
-        todo!()
-    }
-    if let Some(_val) = p.pci_domain {
-        todo!()
-    }
-    if let Some(_val) = p.pci_function {
-        todo!()
-    }
-    if let Some(_val) = p.per_view_position_all_components {
-        todo!()
-    }
-    if let Some(_val) = p.pipeline_cache_uuid {
-        todo!()
-    }
-    if let Some(_val) = p.point_clipping_behavior {
-        todo!()
-    }
-    if let Some(_val) = p.point_size_granularity {
-        todo!()
-    }
-    if let Some(_val) = p.point_size_range {
-        todo!()
-    }
-    if let Some(_val) = p.primitive_fragment_shading_rate_with_multiple_viewports {
-        todo!()
-    }
-    if let Some(_val) = p.primitive_overestimation_size {
-        todo!()
-    }
-    if let Some(_val) = p.primitive_underestimation {
-        todo!()
-    }
-    if let Some(_val) = p.protected_no_fault {
-        todo!()
-    }
-    if let Some(_val) = p.quad_divergent_implicit_lod {
-        todo!()
-    }
-    if let Some(_val) = p.quad_operations_in_all_stages {
-        todo!()
-    }
-    if let Some(_val) = p.required_subgroup_size_stages {
-        todo!()
-    }
-    if let Some(_val) = p.residency_aligned_mip_size {
-        todo!()
-    }
-    if let Some(_val) = p.residency_non_resident_strict {
-        todo!()
-    }
-    if let Some(_val) = p.residency_standard2_d_block_shape {
-        todo!()
-    }
-    if let Some(_val) = p.residency_standard2_d_multisample_block_shape {
-        todo!()
-    }
-    if let Some(_val) = p.residency_standard3_d_block_shape {
-        todo!()
-    }
-    if let Some(_val) = p.robust_buffer_access_update_after_bind {
-        todo!()
-    }
-    if let Some(_val) = p.robust_storage_buffer_access_size_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.robust_uniform_buffer_access_size_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.rounding_mode_independence {
-        todo!()
-    }
-    if let Some(_val) = p.sample_location_coordinate_range {
-        todo!()
-    }
-    if let Some(_val) = p.sample_location_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.sample_location_sub_pixel_bits {
-        todo!()
-    }
-    if let Some(_val) = p.sampled_image_color_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.sampled_image_depth_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.sampled_image_integer_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.sampled_image_stencil_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.sgpr_allocation_granularity {
-        todo!()
-    }
-    if let Some(_val) = p.sgprs_per_simd {
-        todo!()
-    }
-    if let Some(_val) = p.shader_arrays_per_engine_count {
-        todo!()
-    }
-    if let Some(_val) = p.shader_core_features {
-        todo!()
-    }
-    if let Some(_val) = p.shader_denorm_flush_to_zero_float16 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_denorm_flush_to_zero_float32 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_denorm_flush_to_zero_float64 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_denorm_preserve_float16 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_denorm_preserve_float32 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_denorm_preserve_float64 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_engine_count {
-        todo!()
-    }
-    if let Some(_val) = p.shader_group_base_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.shader_group_handle_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.shader_group_handle_capture_replay_size {
-        todo!()
-    }
-    if let Some(_val) = p.shader_group_handle_size {
-        todo!()
-    }
-    if let Some(_val) = p.shader_input_attachment_array_non_uniform_indexing_native {
-        todo!()
-    }
-    if let Some(_val) = p.shader_rounding_mode_rte_float16 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_rounding_mode_rte_float32 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_rounding_mode_rte_float64 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_rounding_mode_rtz_float16 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_rounding_mode_rtz_float32 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_rounding_mode_rtz_float64 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_sampled_image_array_non_uniform_indexing_native {
-        todo!()
-    }
-    if let Some(_val) = p.shader_signed_zero_inf_nan_preserve_float16 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_signed_zero_inf_nan_preserve_float32 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_signed_zero_inf_nan_preserve_float64 {
-        todo!()
-    }
-    if let Some(_val) = p.shader_sm_count {
-        todo!()
-    }
-    if let Some(_val) = p.shader_storage_buffer_array_non_uniform_indexing_native {
-        todo!()
-    }
-    if let Some(_val) = p.shader_storage_image_array_non_uniform_indexing_native {
-        todo!()
-    }
-    if let Some(_val) = p.shader_uniform_buffer_array_non_uniform_indexing_native {
-        todo!()
-    }
-    if let Some(_val) = p.shader_warps_per_sm {
-        todo!()
-    }
-    if let Some(_val) = p.shading_rate_max_coarse_samples {
-        todo!()
-    }
-    if let Some(_val) = p.shading_rate_palette_size {
-        todo!()
-    }
-    if let Some(_val) = p.shading_rate_texel_size {
-        todo!()
-    }
-    if let Some(_val) = p.simd_per_compute_unit {
-        todo!()
-    }
-    if let Some(_val) = p.sparse_address_space_size {
-        todo!()
-    }
-    if let Some(_val) = p.standard_sample_locations {
-        todo!()
-    }
-    if let Some(_val) = p.storage_image_sample_counts {
-        todo!()
-    }
-    if let Some(_val) = p.storage_texel_buffer_offset_alignment_bytes {
-        todo!()
-    }
-    if let Some(_val) = p.storage_texel_buffer_offset_single_texel_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.strict_lines {
-        todo!()
-    }
-    if let Some(_val) = p.sub_pixel_interpolation_offset_bits {
-        todo!()
-    }
-    if let Some(_val) = p.sub_pixel_precision_bits {
-        todo!()
-    }
-    if let Some(_val) = p.sub_texel_precision_bits {
-        todo!()
-    }
-    if let Some(_val) = p.subgroup_quad_operations_in_all_stages {
-        todo!()
-    }
-    if let Some(_val) = p.subgroup_size {
-        todo!()
-    }
-    if let Some(_val) = p.subgroup_supported_operations {
-        todo!()
-    }
-    if let Some(_val) = p.subgroup_supported_stages {
-        todo!()
-    }
-    if let Some(_val) = p.subsampled_coarse_reconstruction_early_access {
-        todo!()
-    }
-    if let Some(_val) = p.subsampled_loads {
-        todo!()
-    }
-    if let Some(_val) = p.supported_depth_resolve_modes {
-        todo!()
-    }
-    if let Some(_val) = p.supported_operations {
-        todo!()
-    }
-    if let Some(_val) = p.supported_stages {
-        todo!()
-    }
-    if let Some(_val) = p.supported_stencil_resolve_modes {
-        todo!()
-    }
-    if let Some(_val) = p.timestamp_compute_and_graphics {
-        todo!()
-    }
-    if let Some(_val) = p.timestamp_period {
-        todo!()
-    }
-    if let Some(_val) = p.transform_feedback_draw {
-        todo!()
-    }
-    if let Some(_val) = p.transform_feedback_queries {
-        todo!()
-    }
-    if let Some(_val) = p.transform_feedback_rasterization_stream_select {
-        todo!()
-    }
-    if let Some(_val) = p.transform_feedback_streams_lines_triangles {
-        todo!()
-    }
-    if let Some(_val) = p.uniform_texel_buffer_offset_alignment_bytes {
-        todo!()
-    }
-    if let Some(_val) = p.uniform_texel_buffer_offset_single_texel_alignment {
-        todo!()
-    }
-    if let Some(_val) = p.variable_sample_locations {
-        todo!()
-    }
-    if let Some(_val) = p.vendor_id {
-        todo!()
-    }
-    if let Some(_val) = p.vgpr_allocation_granularity {
-        todo!()
-    }
-    if let Some(_val) = p.vgprs_per_simd {
-        todo!()
-    }
-    if let Some(_val) = p.viewport_bounds_range {
-        todo!()
-    }
-    if let Some(_val) = p.viewport_sub_pixel_bits {
-        todo!()
-    }
-    if let Some(_val) = p.wavefront_size {
-        todo!()
-    }
-    if let Some(_val) = p.wavefronts_per_simd {
-        todo!()
-    }
+    };
+}
+
+impl_flag_set!(
+    SampleCounts,
+    sample1,
+    sample2,
+    sample4,
+    sample8,
+    sample16,
+    sample32,
+    sample64
+);
+impl_flag_set!(
+    ShaderStages,
+    vertex,
+    tessellation_control,
+    tessellation_evaluation,
+    geometry,
+    fragment,
+    compute
+);
+impl_flag_set!(
+    SubgroupFeatures,
+    basic,
+    vote,
+    arithmetic,
+    ballot,
+    shuffle,
+    shuffle_relative,
+    clustered,
+    quad
+);
+impl_flag_set!(ResolveModes, sample_zero, average, min, max);
+
+// Check whether device property `props.$field` satisfies the requested property
+// `p.$field`. Requested property is ignored if it is `None`. Use,
+//
+// * `eq` for booleans, enums and identity fields like uuid, luid, name etc..
+// * `at_least` for maximum limits, counts and precision bits.
+// * `at_most` for minimum limits, alignments and granularities.
+// * `covers` for `[min, max]` ranges.
+// * `contains` for bit-flag sets, like sample-counts and shader-stages.
+macro_rules! require_property {
+    ($props:ident, $p:ident, $field:ident, eq) => {
+        require_property!(@check, $props, $p, $field, equals)
+    };
+    ($props:ident, $p:ident, $field:ident, at_least) => {
+        require_property!(@check, $props, $p, $field, Limit::at_least)
+    };
+    ($props:ident, $p:ident, $field:ident, at_most) => {
+        require_property!(@check, $props, $p, $field, Limit::at_most)
+    };
+    ($props:ident, $p:ident, $field:ident, covers) => {
+        require_property!(@check, $props, $p, $field, covers)
+    };
+    ($props:ident, $p:ident, $field:ident, contains) => {
+        require_property!(@check, $props, $p, $field, FlagSet::contains)
+    };
+    (@check, $props:ident, $p:ident, $field:ident, $cmp:path) => {
+        if let Some(val) = &$p.$field {
+            match &$props.$field {
+                Some(dval) if $cmp(dval, val) => (),
+                Some(dval) => err_at!(
+                    Vk,
+                    msg: "{}: device {:?} required {:?}",
+                    stringify!($field),
+                    dval,
+                    val
+                )?,
+                None => err_at!(
+                    Vk,
+                    msg: "{}: device None required {:?}",
+                    stringify!($field),
+                    val
+                )?,
+            }
+        }
+    };
+}
+
+// TODO: split this into properties, limits and more...
+fn confirm_properties(p: &Properties, props: &Properties) -> Result<()> {
+    require_property!(props, p, active_compute_unit_count, at_least);
+    require_property!(props, p, advanced_blend_all_operations, eq);
+    require_property!(props, p, advanced_blend_correlated_overlap, eq);
+    require_property!(props, p, advanced_blend_independent_blend, eq);
+    require_property!(props, p, advanced_blend_max_color_attachments, at_least);
+    require_property!(props, p, advanced_blend_non_premultiplied_dst_color, eq);
+    require_property!(props, p, advanced_blend_non_premultiplied_src_color, eq);
+    require_property!(props, p, allow_command_buffer_query_copies, eq);
+    require_property!(props, p, api_version, at_least);
+    require_property!(props, p, buffer_image_granularity, at_most);
+    require_property!(props, p, compute_units_per_shader_array, at_least);
+    require_property!(props, p, conformance_version, eq);
+    require_property!(props, p, conservative_point_and_line_rasterization, eq);
+    require_property!(props, p, conservative_rasterization_post_depth_coverage, eq);
+    require_property!(props, p, cooperative_matrix_supported_stages, contains);
+    require_property!(props, p, degenerate_lines_rasterized, eq);
+    require_property!(props, p, degenerate_triangles_rasterized, eq);
+    require_property!(props, p, denorm_behavior_independence, eq);
+    require_property!(props, p, device_id, eq);
+    require_property!(props, p, device_luid, eq);
+    require_property!(props, p, device_luid_valid, eq);
+    require_property!(props, p, device_name, eq);
+    require_property!(props, p, device_node_mask, eq);
+    require_property!(props, p, device_type, eq);
+    require_property!(props, p, device_uuid, eq);
+    require_property!(props, p, discrete_queue_priorities, at_least);
+    require_property!(props, p, driver_id, eq);
+    require_property!(props, p, driver_info, eq);
+    require_property!(props, p, driver_name, eq);
+    require_property!(props, p, driver_uuid, eq);
+    require_property!(props, p, driver_version, eq);
+    require_property!(
+        props,
+        p,
+        extra_primitive_overestimation_size_granularity,
+        at_most
+    );
+    require_property!(props, p, filter_minmax_image_component_mapping, eq);
+    require_property!(props, p, filter_minmax_single_component_formats, eq);
+    require_property!(props, p, fragment_density_invocations, eq);
+    require_property!(props, p, fragment_shading_rate_non_trivial_combiner_ops, eq);
+    require_property!(props, p, fragment_shading_rate_strict_multiply_combiner, eq);
+    require_property!(
+        props,
+        p,
+        fragment_shading_rate_with_conservative_rasterization,
+        eq
+    );
+    require_property!(
+        props,
+        p,
+        fragment_shading_rate_with_custom_sample_locations,
+        eq
+    );
+    require_property!(
+        props,
+        p,
+        fragment_shading_rate_with_fragment_shader_interlock,
+        eq
+    );
+    require_property!(props, p, fragment_shading_rate_with_sample_mask, eq);
+    require_property!(
+        props,
+        p,
+        fragment_shading_rate_with_shader_depth_stencil_writes,
+        eq
+    );
+    require_property!(props, p, fragment_shading_rate_with_shader_sample_mask, eq);
+    require_property!(props, p, framebuffer_color_sample_counts, contains);
+    require_property!(props, p, framebuffer_depth_sample_counts, contains);
+    require_property!(props, p, framebuffer_integer_color_sample_counts, contains);
+    require_property!(props, p, framebuffer_no_attachments_sample_counts, contains);
+    require_property!(props, p, framebuffer_stencil_sample_counts, contains);
+    require_property!(props, p, fully_covered_fragment_shader_input_variable, eq);
+    require_property!(props, p, independent_resolve, eq);
+    require_property!(props, p, independent_resolve_none, eq);
+    require_property!(props, p, layered_shading_rate_attachments, eq);
+    require_property!(props, p, line_sub_pixel_precision_bits, at_least);
+    require_property!(props, p, line_width_granularity, at_most);
+    require_property!(props, p, line_width_range, covers);
+    require_property!(props, p, max_bound_descriptor_sets, at_least);
+    require_property!(props, p, max_clip_distances, at_least);
+    require_property!(props, p, max_color_attachments, at_least);
+    require_property!(props, p, max_combined_clip_and_cull_distances, at_least);
+    require_property!(props, p, max_compute_shared_memory_size, at_least);
+    require_property!(props, p, max_compute_work_group_count, at_least);
+    require_property!(props, p, max_compute_work_group_invocations, at_least);
+    require_property!(props, p, max_compute_work_group_size, at_least);
+    require_property!(props, p, max_compute_workgroup_subgroups, at_least);
+    require_property!(props, p, max_cull_distances, at_least);
+    require_property!(props, p, max_custom_border_color_samplers, at_least);
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_acceleration_structures,
+        at_least
+    );
+    require_property!(props, p, max_descriptor_set_inline_uniform_blocks, at_least);
+    require_property!(props, p, max_descriptor_set_input_attachments, at_least);
+    require_property!(props, p, max_descriptor_set_sampled_images, at_least);
+    require_property!(props, p, max_descriptor_set_samplers, at_least);
+    require_property!(props, p, max_descriptor_set_storage_buffers, at_least);
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_storage_buffers_dynamic,
+        at_least
+    );
+    require_property!(props, p, max_descriptor_set_storage_images, at_least);
+    require_property!(props, p, max_descriptor_set_subsampled_samplers, at_least);
+    require_property!(props, p, max_descriptor_set_uniform_buffers, at_least);
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_uniform_buffers_dynamic,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_acceleration_structures,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_inline_uniform_blocks,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_input_attachments,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_sampled_images,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_samplers,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_storage_buffers,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_storage_buffers_dynamic,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_storage_images,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_uniform_buffers,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_descriptor_set_update_after_bind_uniform_buffers_dynamic,
+        at_least
+    );
+    require_property!(props, p, max_discard_rectangles, at_least);
+    require_property!(props, p, max_draw_indexed_index_value, at_least);
+    require_property!(props, p, max_draw_indirect_count, at_least);
+    require_property!(props, p, max_draw_mesh_tasks_count, at_least);
+    require_property!(props, p, max_extra_primitive_overestimation_size, at_least);
+    require_property!(props, p, max_fragment_combined_output_resources, at_least);
+    require_property!(props, p, max_fragment_density_texel_size, at_least);
+    require_property!(props, p, max_fragment_dual_src_attachments, at_least);
+    require_property!(props, p, max_fragment_input_components, at_least);
+    require_property!(props, p, max_fragment_output_attachments, at_least);
+    require_property!(
+        props,
+        p,
+        max_fragment_shading_rate_attachment_texel_size,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_fragment_shading_rate_attachment_texel_size_aspect_ratio,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_fragment_shading_rate_coverage_samples,
+        at_least
+    );
+    require_property!(props, p, max_fragment_shading_rate_invocation_count, eq);
+    require_property!(
+        props,
+        p,
+        max_fragment_shading_rate_rasterization_samples,
+        eq
+    );
+    require_property!(props, p, max_fragment_size, at_least);
+    require_property!(props, p, max_fragment_size_aspect_ratio, at_least);
+    require_property!(props, p, max_framebuffer_height, at_least);
+    require_property!(props, p, max_framebuffer_layers, at_least);
+    require_property!(props, p, max_framebuffer_width, at_least);
+    require_property!(props, p, max_geometry_count, at_least);
+    require_property!(props, p, max_geometry_input_components, at_least);
+    require_property!(props, p, max_geometry_output_components, at_least);
+    require_property!(props, p, max_geometry_output_vertices, at_least);
+    require_property!(props, p, max_geometry_shader_invocations, at_least);
+    require_property!(props, p, max_geometry_total_output_components, at_least);
+    require_property!(props, p, max_graphics_shader_group_count, at_least);
+    require_property!(props, p, max_image_array_layers, at_least);
+    require_property!(props, p, max_image_dimension1_d, at_least);
+    require_property!(props, p, max_image_dimension2_d, at_least);
+    require_property!(props, p, max_image_dimension3_d, at_least);
+    require_property!(props, p, max_image_dimension_cube, at_least);
+    require_property!(props, p, max_indirect_commands_stream_count, at_least);
+    require_property!(props, p, max_indirect_commands_stream_stride, at_least);
+    require_property!(props, p, max_indirect_commands_token_count, at_least);
+    require_property!(props, p, max_indirect_commands_token_offset, at_least);
+    require_property!(props, p, max_indirect_sequence_count, at_least);
+    require_property!(props, p, max_inline_uniform_block_size, at_least);
+    require_property!(props, p, max_instance_count, at_least);
+    require_property!(props, p, max_interpolation_offset, at_least);
+    require_property!(props, p, max_memory_allocation_count, at_least);
+    require_property!(props, p, max_memory_allocation_size, at_least);
+    require_property!(props, p, max_mesh_multiview_view_count, at_least);
+    require_property!(props, p, max_mesh_output_primitives, at_least);
+    require_property!(props, p, max_mesh_output_vertices, at_least);
+    require_property!(props, p, max_mesh_total_memory_size, at_least);
+    require_property!(props, p, max_mesh_work_group_invocations, at_least);
+    require_property!(props, p, max_mesh_work_group_size, at_least);
+    require_property!(props, p, max_multiview_instance_index, at_least);
+    require_property!(props, p, max_multiview_view_count, at_least);
+    require_property!(props, p, max_per_set_descriptors, at_least);
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_acceleration_structures,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_inline_uniform_blocks,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_input_attachments,
+        at_least
+    );
+    require_property!(props, p, max_per_stage_descriptor_sampled_images, at_least);
+    require_property!(props, p, max_per_stage_descriptor_samplers, at_least);
+    require_property!(props, p, max_per_stage_descriptor_storage_buffers, at_least);
+    require_property!(props, p, max_per_stage_descriptor_storage_images, at_least);
+    require_property!(props, p, max_per_stage_descriptor_uniform_buffers, at_least);
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_update_after_bind_acceleration_structures,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_update_after_bind_inline_uniform_blocks,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_update_after_bind_input_attachments,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_update_after_bind_sampled_images,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_update_after_bind_samplers,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_update_after_bind_storage_buffers,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_update_after_bind_storage_images,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_per_stage_descriptor_update_after_bind_uniform_buffers,
+        at_least
+    );
+    require_property!(props, p, max_per_stage_resources, at_least);
+    require_property!(
+        props,
+        p,
+        max_per_stage_update_after_bind_resources,
+        at_least
+    );
+    require_property!(props, p, max_primitive_count, at_least);
+    require_property!(props, p, max_push_constants_size, at_least);
+    require_property!(props, p, max_push_descriptors, at_least);
+    require_property!(props, p, max_ray_dispatch_invocation_count, at_least);
+    require_property!(props, p, max_ray_hit_attribute_size, at_least);
+    require_property!(props, p, max_ray_recursion_depth, at_least);
+    require_property!(props, p, max_recursion_depth, at_least);
+    require_property!(props, p, max_sample_location_grid_size, at_least);
+    require_property!(props, p, max_sample_mask_words, at_least);
+    require_property!(props, p, max_sampler_allocation_count, at_least);
+    require_property!(props, p, max_sampler_anisotropy, at_least);
+    require_property!(props, p, max_sampler_lod_bias, at_least);
+    require_property!(props, p, max_sgpr_allocation, at_least);
+    require_property!(props, p, max_shader_group_stride, at_least);
+    require_property!(props, p, max_storage_buffer_range, at_least);
+    require_property!(props, p, max_subgroup_size, at_least);
+    require_property!(props, p, max_subsampled_array_layers, at_least);
+    require_property!(props, p, max_task_output_count, at_least);
+    require_property!(props, p, max_task_total_memory_size, at_least);
+    require_property!(props, p, max_task_work_group_invocations, at_least);
+    require_property!(props, p, max_task_work_group_size, at_least);
+    require_property!(
+        props,
+        p,
+        max_tessellation_control_per_patch_output_components,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_tessellation_control_per_vertex_input_components,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_tessellation_control_per_vertex_output_components,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_tessellation_control_total_output_components,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_tessellation_evaluation_input_components,
+        at_least
+    );
+    require_property!(
+        props,
+        p,
+        max_tessellation_evaluation_output_components,
+        at_least
+    );
+    require_property!(props, p, max_tessellation_generation_level, at_least);
+    require_property!(props, p, max_tessellation_patch_size, at_least);
+    require_property!(props, p, max_texel_buffer_elements, at_least);
+    require_property!(props, p, max_texel_gather_offset, at_least);
+    require_property!(props, p, max_texel_offset, at_least);
+    require_property!(props, p, max_timeline_semaphore_value_difference, at_least);
+    require_property!(props, p, max_transform_feedback_buffer_data_size, at_least);
+    require_property!(
+        props,
+        p,
+        max_transform_feedback_buffer_data_stride,
+        at_least
+    );
+    require_property!(props, p, max_transform_feedback_buffer_size, at_least);
+    require_property!(props, p, max_transform_feedback_buffers, at_least);
+    require_property!(props, p, max_transform_feedback_stream_data_size, at_least);
+    require_property!(props, p, max_transform_feedback_streams, at_least);
+    require_property!(props, p, max_triangle_count, at_least);
+    require_property!(props, p, max_uniform_buffer_range, at_least);
+    require_property!(
+        props,
+        p,
+        max_update_after_bind_descriptors_in_all_pools,
+        at_least
+    );
+    require_property!(props, p, max_vertex_attrib_divisor, at_least);
+    require_property!(props, p, max_vertex_input_attribute_offset, at_least);
+    require_property!(props, p, max_vertex_input_attributes, at_least);
+    require_property!(props, p, max_vertex_input_binding_stride, at_least);
+    require_property!(props, p, max_vertex_input_bindings, at_least);
+    require_property!(props, p, max_vertex_output_components, at_least);
+    require_property!(props, p, max_vgpr_allocation, at_least);
+    require_property!(props, p, max_viewport_dimensions, at_least);
+    require_property!(props, p, max_viewports, at_least);
+    require_property!(props, p, mesh_output_per_primitive_granularity, at_most);
+    require_property!(props, p, mesh_output_per_vertex_granularity, at_most);
+    require_property!(
+        props,
+        p,
+        min_acceleration_structure_scratch_offset_alignment,
+        at_most
+    );
+    require_property!(props, p, min_fragment_density_texel_size, at_most);
+    require_property!(
+        props,
+        p,
+        min_fragment_shading_rate_attachment_texel_size,
+        at_most
+    );
+    require_property!(props, p, min_imported_host_pointer_alignment, at_most);
+    require_property!(
+        props,
+        p,
+        min_indirect_commands_buffer_offset_alignment,
+        at_most
+    );
+    require_property!(props, p, min_interpolation_offset, at_most);
+    require_property!(props, p, min_memory_map_alignment, at_most);
+    require_property!(
+        props,
+        p,
+        min_sequences_count_buffer_offset_alignment,
+        at_most
+    );
+    require_property!(
+        props,
+        p,
+        min_sequences_index_buffer_offset_alignment,
+        at_most
+    );
+    require_property!(props, p, min_sgpr_allocation, at_most);
+    require_property!(props, p, min_storage_buffer_offset_alignment, at_most);
+    require_property!(props, p, min_subgroup_size, at_most);
+    require_property!(props, p, min_texel_buffer_offset_alignment, at_most);
+    require_property!(props, p, min_texel_gather_offset, at_most);
+    require_property!(props, p, min_texel_offset, at_most);
+    require_property!(props, p, min_uniform_buffer_offset_alignment, at_most);
+    require_property!(props, p, min_vertex_input_binding_stride_alignment, at_most);
+    require_property!(props, p, min_vgpr_allocation, at_most);
+    require_property!(props, p, mipmap_precision_bits, at_least);
+    require_property!(props, p, non_coherent_atom_size, at_most);
+    require_property!(props, p, optimal_buffer_copy_offset_alignment, at_most);
+    require_property!(props, p, optimal_buffer_copy_row_pitch_alignment, at_most);
+    require_property!(props, p, pci_bus, eq);
+    require_property!(props, p, pci_device, eq);
+    require_property!(props, p, pci_domain, eq);
+    require_property!(props, p, pci_function, eq);
+    require_property!(props, p, per_view_position_all_components, eq);
+    require_property!(props, p, pipeline_cache_uuid, eq);
+    require_property!(props, p, point_clipping_behavior, eq);
+    require_property!(props, p, point_size_granularity, at_most);
+    require_property!(props, p, point_size_range, covers);
+    require_property!(
+        props,
+        p,
+        primitive_fragment_shading_rate_with_multiple_viewports,
+        eq
+    );
+    require_property!(props, p, primitive_overestimation_size, at_most);
+    require_property!(props, p, primitive_underestimation, eq);
+    require_property!(props, p, protected_no_fault, eq);
+    require_property!(props, p, quad_divergent_implicit_lod, eq);
+    require_property!(props, p, quad_operations_in_all_stages, eq);
+    require_property!(props, p, required_subgroup_size_stages, contains);
+    require_property!(props, p, residency_aligned_mip_size, eq);
+    require_property!(props, p, residency_non_resident_strict, eq);
+    require_property!(props, p, residency_standard2_d_block_shape, eq);
+    require_property!(props, p, residency_standard2_d_multisample_block_shape, eq);
+    require_property!(props, p, residency_standard3_d_block_shape, eq);
+    require_property!(props, p, robust_buffer_access_update_after_bind, eq);
+    require_property!(
+        props,
+        p,
+        robust_storage_buffer_access_size_alignment,
+        at_most
+    );
+    require_property!(
+        props,
+        p,
+        robust_uniform_buffer_access_size_alignment,
+        at_most
+    );
+    require_property!(props, p, rounding_mode_independence, eq);
+    require_property!(props, p, sample_location_coordinate_range, covers);
+    require_property!(props, p, sample_location_sample_counts, contains);
+    require_property!(props, p, sample_location_sub_pixel_bits, at_least);
+    require_property!(props, p, sampled_image_color_sample_counts, contains);
+    require_property!(props, p, sampled_image_depth_sample_counts, contains);
+    require_property!(props, p, sampled_image_integer_sample_counts, contains);
+    require_property!(props, p, sampled_image_stencil_sample_counts, contains);
+    require_property!(props, p, sgpr_allocation_granularity, at_most);
+    require_property!(props, p, sgprs_per_simd, at_least);
+    require_property!(props, p, shader_arrays_per_engine_count, at_least);
+    require_property!(props, p, shader_core_features, eq);
+    require_property!(props, p, shader_denorm_flush_to_zero_float16, eq);
+    require_property!(props, p, shader_denorm_flush_to_zero_float32, eq);
+    require_property!(props, p, shader_denorm_flush_to_zero_float64, eq);
+    require_property!(props, p, shader_denorm_preserve_float16, eq);
+    require_property!(props, p, shader_denorm_preserve_float32, eq);
+    require_property!(props, p, shader_denorm_preserve_float64, eq);
+    require_property!(props, p, shader_engine_count, at_least);
+    require_property!(props, p, shader_group_base_alignment, at_most);
+    require_property!(props, p, shader_group_handle_alignment, at_most);
+    require_property!(props, p, shader_group_handle_capture_replay_size, eq);
+    require_property!(props, p, shader_group_handle_size, eq);
+    require_property!(
+        props,
+        p,
+        shader_input_attachment_array_non_uniform_indexing_native,
+        eq
+    );
+    require_property!(props, p, shader_rounding_mode_rte_float16, eq);
+    require_property!(props, p, shader_rounding_mode_rte_float32, eq);
+    require_property!(props, p, shader_rounding_mode_rte_float64, eq);
+    require_property!(props, p, shader_rounding_mode_rtz_float16, eq);
+    require_property!(props, p, shader_rounding_mode_rtz_float32, eq);
+    require_property!(props, p, shader_rounding_mode_rtz_float64, eq);
+    require_property!(
+        props,
+        p,
+        shader_sampled_image_array_non_uniform_indexing_native,
+        eq
+    );
+    require_property!(props, p, shader_signed_zero_inf_nan_preserve_float16, eq);
+    require_property!(props, p, shader_signed_zero_inf_nan_preserve_float32, eq);
+    require_property!(props, p, shader_signed_zero_inf_nan_preserve_float64, eq);
+    require_property!(props, p, shader_sm_count, at_least);
+    require_property!(
+        props,
+        p,
+        shader_storage_buffer_array_non_uniform_indexing_native,
+        eq
+    );
+    require_property!(
+        props,
+        p,
+        shader_storage_image_array_non_uniform_indexing_native,
+        eq
+    );
+    require_property!(
+        props,
+        p,
+        shader_uniform_buffer_array_non_uniform_indexing_native,
+        eq
+    );
+    require_property!(props, p, shader_warps_per_sm, at_least);
+    require_property!(props, p, shading_rate_max_coarse_samples, at_least);
+    require_property!(props, p, shading_rate_palette_size, at_least);
+    require_property!(props, p, shading_rate_texel_size, eq);
+    require_property!(props, p, simd_per_compute_unit, at_least);
+    require_property!(props, p, sparse_address_space_size, at_least);
+    require_property!(props, p, standard_sample_locations, eq);
+    require_property!(props, p, storage_image_sample_counts, contains);
+    require_property!(
+        props,
+        p,
+        storage_texel_buffer_offset_alignment_bytes,
+        at_most
+    );
+    require_property!(
+        props,
+        p,
+        storage_texel_buffer_offset_single_texel_alignment,
+        eq
+    );
+    require_property!(props, p, strict_lines, eq);
+    require_property!(props, p, sub_pixel_interpolation_offset_bits, at_least);
+    require_property!(props, p, sub_pixel_precision_bits, at_least);
+    require_property!(props, p, sub_texel_precision_bits, at_least);
+    require_property!(props, p, subgroup_quad_operations_in_all_stages, eq);
+    require_property!(props, p, subgroup_size, eq);
+    require_property!(props, p, subgroup_supported_operations, contains);
+    require_property!(props, p, subgroup_supported_stages, contains);
+    require_property!(props, p, subsampled_coarse_reconstruction_early_access, eq);
+    require_property!(props, p, subsampled_loads, eq);
+    require_property!(props, p, supported_depth_resolve_modes, contains);
+    require_property!(props, p, supported_operations, contains);
+    require_property!(props, p, supported_stages, contains);
+    require_property!(props, p, supported_stencil_resolve_modes, contains);
+    require_property!(props, p, timestamp_compute_and_graphics, eq);
+    require_property!(props, p, timestamp_period, at_most);
+    require_property!(props, p, transform_feedback_draw, eq);
+    require_property!(props, p, transform_feedback_queries, eq);
+    require_property!(props, p, transform_feedback_rasterization_stream_select, eq);
+    require_property!(props, p, transform_feedback_streams_lines_triangles, eq);
+    require_property!(
+        props,
+        p,
+        uniform_texel_buffer_offset_alignment_bytes,
+        at_most
+    );
+    require_property!(
+        props,
+        p,
+        uniform_texel_buffer_offset_single_texel_alignment,
+        eq
+    );
+    require_property!(props, p, variable_sample_locations, eq);
+    require_property!(props, p, vendor_id, eq);
+    require_property!(props, p, vgpr_allocation_granularity, at_most);
+    require_property!(props, p, vgprs_per_simd, at_least);
+    require_property!(props, p, viewport_bounds_range, covers);
+    require_property!(props, p, viewport_sub_pixel_bits, at_least);
+    require_property!(props, p, wavefront_size, eq);
+    require_property!(props, p, wavefronts_per_simd, at_least);
 
     Ok(())
 }
@@ -1810,3 +1962,194 @@ fn union_iextns(a: InstanceExtensions, b: InstanceExtensions) -> InstanceExtensi
         _unbuildable: a._unbuildable,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // needs a vulkan driver and a device supporting khr_swapchain, run with
+    // `cargo test -- --ignored`.
+    #[test]
+    #[ignore]
+    fn test_recreate_swapchain_bitmap() {
+        let mut vobj: Vulkan = Builder::new()
+            .unwrap()
+            .build_for_buffer([64, 64], Format::R8G8B8A8Unorm)
+            .unwrap();
+        assert!(vobj.recreate_swapchain([128, 128]).is_err());
+    }
+
+    #[test]
+    fn test_add_validation_layer() {
+        let to_strings = |names: &[&str]| -> Vec<String> {
+            names.iter().map(|s| s.to_string()).collect()
+        };
+        let (khronos, lunarg) = (VALIDATION_LAYERS[0], VALIDATION_LAYERS[1]);
+
+        // khronos preferred over lunarg.
+        let available = to_strings(&["VK_LAYER_MESA_overlay", lunarg, khronos]);
+        let layers =
+            add_validation_layer(to_strings(&["VK_LAYER_MESA_overlay"]), &available);
+        assert_eq!(layers, to_strings(&["VK_LAYER_MESA_overlay", khronos]));
+
+        // fallback to lunarg.
+        let available = to_strings(&[lunarg]);
+        assert_eq!(
+            add_validation_layer(vec![], &available),
+            to_strings(&[lunarg])
+        );
+
+        // no duplicate, if already added via with_layers.
+        let available = to_strings(&[khronos, lunarg]);
+        let layers = add_validation_layer(to_strings(&[khronos]), &available);
+        assert_eq!(layers, to_strings(&[khronos]));
+
+        // no-op when validation layers are not available.
+        let available = to_strings(&["VK_LAYER_MESA_overlay"]);
+        let layers =
+            add_validation_layer(to_strings(&["VK_LAYER_MESA_overlay"]), &available);
+        assert_eq!(layers, to_strings(&["VK_LAYER_MESA_overlay"]));
+        assert!(add_validation_layer(vec![], &[]).is_empty());
+    }
+
+    fn sample_counts(counts: &[u32]) -> SampleCounts {
+        SampleCounts {
+            sample1: counts.contains(&1),
+            sample2: counts.contains(&2),
+            sample4: counts.contains(&4),
+            sample8: counts.contains(&8),
+            sample16: counts.contains(&16),
+            sample32: counts.contains(&32),
+            sample64: counts.contains(&64),
+        }
+    }
+
+    #[test]
+    fn test_confirm_properties() {
+        let required = Properties {
+            max_compute_work_group_invocations: Some(1024),
+            min_uniform_buffer_offset_alignment: Some(256),
+            ..Properties::default()
+        };
+        let props = Properties {
+            max_compute_work_group_invocations: Some(1024),
+            min_uniform_buffer_offset_alignment: Some(64),
+            ..Properties::default()
+        };
+        confirm_properties(&required, &props).unwrap();
+
+        // nothing requested
+        confirm_properties(&Properties::default(), &props).unwrap();
+    }
+
+    #[test]
+    fn test_confirm_properties_too_low() {
+        let required = Properties {
+            max_compute_work_group_invocations: Some(1024),
+            ..Properties::default()
+        };
+        let props = Properties {
+            max_compute_work_group_invocations: Some(64),
+            ..Properties::default()
+        };
+        assert!(confirm_properties(&required, &props).is_err());
+
+        let required = Properties {
+            min_uniform_buffer_offset_alignment: Some(64),
+            ..Properties::default()
+        };
+        let props = Properties {
+            min_uniform_buffer_offset_alignment: Some(256),
+            ..Properties::default()
+        };
+        assert!(confirm_properties(&required, &props).is_err());
+    }
+
+    #[test]
+    fn test_confirm_properties_none() {
+        let required = Properties {
+            max_compute_work_group_invocations: Some(1024),
+            ..Properties::default()
+        };
+        let props = Properties::default();
+        assert!(confirm_properties(&required, &props).is_err());
+    }
+
+    #[test]
+    fn test_confirm_properties_eq() {
+        let required = Properties {
+            device_type: Some(PhysicalDeviceType::DiscreteGpu),
+            ..Properties::default()
+        };
+        let props = Properties {
+            device_type: Some(PhysicalDeviceType::IntegratedGpu),
+            ..Properties::default()
+        };
+        assert!(confirm_properties(&required, &props).is_err());
+        confirm_properties(&required, &required).unwrap();
+    }
+
+    #[test]
+    fn test_confirm_properties_covers() {
+        let props = Properties {
+            point_size_range: Some([1.0, 64.0]),
+            ..Properties::default()
+        };
+
+        let required = Properties {
+            point_size_range: Some([1.0, 32.0]),
+            ..Properties::default()
+        };
+        confirm_properties(&required, &props).unwrap();
+
+        let required = Properties {
+            point_size_range: Some([1.0, 256.0]),
+            ..Properties::default()
+        };
+        assert!(confirm_properties(&required, &props).is_err());
+
+        let required = Properties {
+            point_size_range: Some([0.5, 32.0]),
+            ..Properties::default()
+        };
+        assert!(confirm_properties(&required, &props).is_err());
+    }
+
+    #[test]
+    fn test_confirm_properties_flags() {
+        let props = Properties {
+            framebuffer_color_sample_counts: Some(sample_counts(&[1, 2, 4, 8])),
+            ..Properties::default()
+        };
+
+        for (counts, ok) in [(&[4][..], true), (&[1, 2, 4, 8], true), (&[4, 16], false)] {
+            let required = Properties {
+                framebuffer_color_sample_counts: Some(sample_counts(counts)),
+                ..Properties::default()
+            };
+            assert_eq!(
+                confirm_properties(&required, &props).is_ok(),
+                ok,
+                "{:?}",
+                counts
+            );
+        }
+    }
+
+    // vulkano-0.24 cannot create a surface via ext_headless_surface, hence this
+    // test needs a display. And event-loop shall be created in the main thread on
+    // some platforms, run with `cargo test -- --ignored --test-threads=1`.
+    #[test]
+    #[ignore]
+    fn test_recreate_swapchain_surface() {
+        let mut vobj: Vulkan = Builder::new()
+            .unwrap()
+            .build_for_surface(vulkano_win::required_extensions())
+            .unwrap();
+        assert!(vobj.recreate_swapchain([128, 128]).is_err());
+
+        vobj.create_swapchain(None).unwrap();
+        vobj.recreate_swapchain([128, 96]).unwrap();
+        assert_eq!(vobj.to_swapchain().dimensions(), [128, 96]);
+    }
+}
@@ -262,6 +262,11 @@ fn info_device(_opts: Opt) -> Result<()> {
     println!("{}: {}", "Number of physical devices".yellow(), pds.len());
     println!();
 
+    println!("{}", "List of physical devices".yellow());
+    let infos = Vulkan::list_physical_devices(&vobj.to_instance());
+    make_table(&infos).print_tty(force_color);
+    println!();
+
     println!("{}", "List of layers".yellow());
     make_table(&layers).print_tty(force_color);
     println!();